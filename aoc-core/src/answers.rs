@@ -0,0 +1,128 @@
+use crate::{Answer, Part};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Location of the answers file shared by every day's tests and `aoc verify`.
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Value> for Answer {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Number(n) => Answer::Number(n as i128),
+            Value::Text(s) => s.parse().map_or(Answer::Text(s), Answer::Number),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+/// Known answers, keyed by day, input name (the file stem, e.g. `test`) and part.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, BTreeMap<String, [Option<Answer>; 2]>>,
+}
+
+impl Answers {
+    pub fn load() -> Result<Self, String> {
+        let path = answers_path();
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+
+        Self::parse(&contents).map_err(|e| format!("invalid {}: {e}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let raw: BTreeMap<String, BTreeMap<String, Entry>> =
+            toml::from_str(contents).map_err(|e| e.to_string())?;
+
+        let mut days = BTreeMap::new();
+        for (key, inputs) in raw {
+            let day = key
+                .strip_prefix("day-")
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(|| format!("expected a `day-NN` table, found `{key}`"))?;
+
+            let inputs = inputs
+                .into_iter()
+                .map(|(name, entry)| {
+                    let parts = [entry.part1.map(Answer::from), entry.part2.map(Answer::from)];
+                    (name, parts)
+                })
+                .collect();
+
+            days.insert(day, inputs);
+        }
+
+        Ok(Self { days })
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        self.days.get(&day)?.get(input)?[part.number() as usize - 1].as_ref()
+    }
+
+    /// Names of the inputs with at least one recorded answer for `day`.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.days
+            .get(&day)
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+}
+
+/// The recorded answer for a day's input and part, for use in tests.
+pub fn expected(day: u8, input: &str, part: Part) -> Answer {
+    let answers = Answers::load().unwrap_or_else(|e| panic!("{e}"));
+
+    answers
+        .get(day, input, part)
+        .cloned()
+        .unwrap_or_else(|| panic!("no answer recorded for day {day} {input} {part}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part::{Part1, Part2};
+
+    #[test]
+    fn reads_numbers_and_strings() {
+        let answers = Answers::parse(
+            "[day-03.test]\npart1 = 357\npart2 = \"3121910778619\"\n\n[day-11.test2]\npart2 = \"abc\"",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(3, "test", Part1), Some(&Answer::Number(357)));
+        assert_eq!(
+            answers.get(3, "test", Part2),
+            Some(&Answer::Number(3121910778619))
+        );
+        assert_eq!(
+            answers.get(11, "test2", Part2),
+            Some(&Answer::Text("abc".to_string()))
+        );
+        assert_eq!(answers.get(11, "test2", Part1), None);
+        assert_eq!(answers.inputs(11).collect::<Vec<_>>(), ["test2"]);
+    }
+
+    #[test]
+    fn rejects_unknown_tables() {
+        assert!(Answers::parse("[dec-01.test]\npart1 = 1").is_err());
+    }
+}
@@ -0,0 +1,212 @@
+//! Running the same work over many independent items, such as the lines of
+//! an input, and collecting the results in the items' order.
+//!
+//! With the `parallel` feature the items are shared among rayon's threads;
+//! without it they run one after another. Either way each item can be given
+//! a time limit, which the work checks through its [`Deadline`], and long
+//! batches can report their progress on stderr.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// How a runner wants batches run, passed down to the days that split
+/// their work into them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// The time each item gets, or `None` for no limit.
+    pub timeout: Option<Duration>,
+    /// Whether to count the finished items on stderr.
+    pub progress: bool,
+}
+
+/// The moment an item's work should give up, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline(Option<Instant>);
+
+impl Deadline {
+    pub const NEVER: Deadline = Deadline(None);
+
+    /// `timeout` from now, or never if that is too far off to represent.
+    pub fn after(timeout: Duration) -> Self {
+        Deadline(Instant::now().checked_add(timeout))
+    }
+
+    pub fn has_passed(&self) -> bool {
+        self.0.is_some_and(|at| Instant::now() >= at)
+    }
+
+    pub fn check(&self) -> Result<(), TimedOut> {
+        if self.has_passed() {
+            Err(TimedOut)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut;
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "timed out")
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+#[derive(Debug, Clone)]
+pub struct Batch {
+    /// What the items are, for the progress line.
+    label: &'static str,
+    timeout: Option<Duration>,
+    progress: bool,
+}
+
+impl Batch {
+    /// A batch with no time limit that runs quietly.
+    pub fn new(label: &'static str) -> Self {
+        Self::with_options(label, BatchOptions::default())
+    }
+
+    pub fn with_options(label: &'static str, options: BatchOptions) -> Self {
+        Self {
+            label,
+            timeout: options.timeout,
+            progress: options.progress,
+        }
+    }
+
+    /// Gives each item `timeout` to finish, or no limit for `None`.
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether to count the finished items on stderr.
+    pub fn progress(mut self, progress: bool) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// `work` applied to every item, each with its own deadline, in the
+    /// items' order.
+    pub fn run<T, R>(
+        &self,
+        items: &[T],
+        work: impl Fn(&T, Deadline) -> Result<R, TimedOut> + Sync,
+    ) -> Vec<Result<R, TimedOut>>
+    where
+        T: Sync,
+        R: Send,
+    {
+        let progress = Progress::new(self.label, items.len(), self.progress);
+        let run_one = |item: &T| {
+            let deadline = self.timeout.map_or(Deadline::NEVER, Deadline::after);
+            let result = work(item, deadline);
+            progress.tick();
+            result
+        };
+
+        #[cfg(feature = "parallel")]
+        let results = items.par_iter().map(run_one).collect();
+        #[cfg(not(feature = "parallel"))]
+        let results = items.iter().map(run_one).collect();
+
+        results
+    }
+
+    /// `work` applied to every item, for work too quick to need a deadline.
+    pub fn map<T, R>(&self, items: &[T], work: impl Fn(&T) -> R + Sync) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+    {
+        self.run(items, |item, _| Ok(work(item)))
+            .into_iter()
+            .map(|result| result.expect("work without a deadline cannot time out"))
+            .collect()
+    }
+}
+
+/// A `label: done/total (percent%)` line on stderr, redrawn each time the
+/// percentage goes up.
+struct Progress {
+    label: &'static str,
+    total: usize,
+    enabled: bool,
+    done: AtomicUsize,
+    shown: AtomicUsize,
+}
+
+impl Progress {
+    fn new(label: &'static str, total: usize, enabled: bool) -> Self {
+        Self {
+            label,
+            total,
+            enabled,
+            done: AtomicUsize::new(0),
+            shown: AtomicUsize::new(0),
+        }
+    }
+
+    fn tick(&self) {
+        if !self.enabled {
+            return;
+        }
+
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        let percent = done * 100 / self.total;
+        if self.shown.fetch_max(percent, Ordering::Relaxed) < percent || done == self.total {
+            eprint!("\r{}: {done}/{} ({percent}%)", self.label, self.total);
+        }
+        if done == self.total {
+            eprintln!();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_items_in_order() {
+        let squares = Batch::new("numbers").map(&(0..1000).collect::<Vec<u64>>(), |n| n * n);
+
+        assert_eq!(squares.len(), 1000);
+        assert!(
+            squares
+                .iter()
+                .enumerate()
+                .all(|(n, &sq)| sq == (n * n) as u64)
+        );
+    }
+
+    #[test]
+    fn times_out_single_items() {
+        let results = Batch::new("sleeps")
+            .timeout(Some(Duration::from_millis(20)))
+            .run(&[0, 1_000], |&millis, deadline| {
+                let start = Instant::now();
+                while start.elapsed() < Duration::from_millis(millis) {
+                    deadline.check()?;
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+                Ok(millis)
+            });
+
+        assert_eq!(results, [Ok(0), Err(TimedOut)]);
+    }
+
+    #[test]
+    fn never_passes_without_a_limit() {
+        assert!(!Deadline::NEVER.has_passed());
+        assert!(Deadline::after(Duration::ZERO).has_passed());
+        assert_eq!(Deadline::after(Duration::MAX), Deadline::NEVER);
+    }
+}
@@ -0,0 +1,311 @@
+use std::cmp::Ordering;
+
+/// Disjoint-set forest with union by rank and path compression.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of `x`'s set, compressing the path to it as it goes.
+    pub fn find(&mut self, x: usize) -> usize {
+        let root = self.root(x);
+
+        let mut node = x;
+        while self.parent[node] != root {
+            node = std::mem::replace(&mut self.parent[node], root);
+        }
+
+        root
+    }
+
+    /// The representative of `x`'s set, without modifying the forest.
+    pub fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `x` and `y`, returning `false` if they were already joined.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+
+        if root_x == root_y {
+            return false;
+        }
+
+        let (root, child) = match self.rank[root_x].cmp(&self.rank[root_y]) {
+            Ordering::Less => (root_y, root_x),
+            Ordering::Greater => (root_x, root_y),
+            Ordering::Equal => {
+                self.rank[root_x] += 1;
+                (root_x, root_y)
+            }
+        };
+
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&self, a: usize, b: usize) -> bool {
+        self.root(a) == self.root(b)
+    }
+
+    /// Number of elements in `x`'s set.
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.root(x)]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Every member of `x`'s set, in ascending order.
+    pub fn component_of(&self, x: usize) -> Vec<usize> {
+        let root = self.root(x);
+        (0..self.len()).filter(|&i| self.root(i) == root).collect()
+    }
+
+    /// The size of every set, in order of each set's representative.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&i| self.parent[i] == i)
+            .map(|i| self.size[i])
+            .collect()
+    }
+}
+
+/// Disjoint-set forest whose unions can be undone, for offline algorithms
+/// that explore a choice and then backtrack. It uses union by size without
+/// path compression, so `find` stays O(log n) and every union is reversible.
+#[derive(Debug, Clone)]
+pub struct RollbackUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    /// The root attached by each successful union, most recent last.
+    history: Vec<usize>,
+}
+
+impl RollbackUnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            history: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `x` and `y`, returning `false` if they were already joined.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut root, mut child) = (self.find(x), self.find(y));
+
+        if root == child {
+            return false;
+        }
+        if self.size[root] < self.size[child] {
+            (root, child) = (child, root);
+        }
+
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        self.history.push(child);
+
+        true
+    }
+
+    pub fn connected(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.len() - self.history.len()
+    }
+
+    /// Every member of `x`'s set, in ascending order.
+    pub fn component_of(&self, x: usize) -> Vec<usize> {
+        let root = self.find(x);
+        (0..self.len()).filter(|&i| self.find(i) == root).collect()
+    }
+
+    /// A marker for the current state, to pass to [`rollback`](Self::rollback).
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes the most recent successful union, returning `false` if there was none.
+    pub fn undo(&mut self) -> bool {
+        let Some(child) = self.history.pop() else {
+            return false;
+        };
+
+        let root = self.parent[child];
+        self.size[root] -= self.size[child];
+        self.parent[child] = child;
+
+        true
+    }
+
+    /// Undoes every union made since `snapshot` was taken.
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    /// Connected components by breadth-first search over the union edges,
+    /// as a label per element.
+    fn bfs_components(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+        let mut adjacency = vec![Vec::new(); n];
+        for &(a, b) in edges {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+
+        let mut label = vec![usize::MAX; n];
+        for start in 0..n {
+            if label[start] != usize::MAX {
+                continue;
+            }
+            label[start] = start;
+            let mut queue = VecDeque::from([start]);
+            while let Some(node) = queue.pop_front() {
+                for &next in &adjacency[node] {
+                    if label[next] == usize::MAX {
+                        label[next] = start;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        label
+    }
+
+    fn edges_strategy() -> impl Strategy<Value = (usize, Vec<(usize, usize)>)> {
+        (1usize..40).prop_flat_map(|n| (Just(n), prop::collection::vec((0..n, 0..n), 0..60)))
+    }
+
+    #[test]
+    fn deep_chains_do_not_overflow() {
+        let n = 1_000_000;
+        let mut sets = RollbackUnionFind::new(n);
+        let mut compressed = UnionFind::new(n);
+        for i in 1..n {
+            sets.union(i - 1, i);
+            compressed.union(i, i - 1);
+        }
+
+        assert_eq!(sets.size_of(0), n);
+        assert_eq!(compressed.find(n - 1), compressed.find(0));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_bfs_connectivity((n, edges) in edges_strategy()) {
+            let mut sets = UnionFind::new(n);
+            for &(a, b) in &edges {
+                sets.union(a, b);
+            }
+            let labels = bfs_components(n, &edges);
+
+            for a in 0..n {
+                for b in 0..n {
+                    prop_assert_eq!(sets.connected(a, b), labels[a] == labels[b]);
+                }
+                let members: Vec<usize> = (0..n).filter(|&i| labels[i] == labels[a]).collect();
+                prop_assert_eq!(sets.size_of(a), members.len());
+                prop_assert_eq!(sets.component_of(a), members);
+            }
+
+            let mut distinct = labels.clone();
+            distinct.sort();
+            distinct.dedup();
+            prop_assert_eq!(sets.component_count(), distinct.len());
+            prop_assert_eq!(sets.component_sizes().iter().sum::<usize>(), n);
+        }
+
+        #[test]
+        fn rollback_restores_earlier_connectivity(
+            (n, edges) in edges_strategy(),
+            split in 0usize..60,
+        ) {
+            let split = split.min(edges.len());
+            let mut sets = RollbackUnionFind::new(n);
+            for &(a, b) in &edges[..split] {
+                sets.union(a, b);
+            }
+
+            let snapshot = sets.snapshot();
+            for &(a, b) in &edges[split..] {
+                sets.union(a, b);
+            }
+            let labels = bfs_components(n, &edges);
+            for a in 0..n {
+                prop_assert_eq!(sets.component_of(a), (0..n).filter(|&i| labels[i] == labels[a]).collect::<Vec<_>>());
+            }
+
+            sets.rollback(snapshot);
+            let labels = bfs_components(n, &edges[..split]);
+            for a in 0..n {
+                for b in 0..n {
+                    prop_assert_eq!(sets.connected(a, b), labels[a] == labels[b]);
+                }
+            }
+            let mut distinct = labels.clone();
+            distinct.sort();
+            distinct.dedup();
+            prop_assert_eq!(sets.component_count(), distinct.len());
+        }
+    }
+}
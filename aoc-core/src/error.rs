@@ -0,0 +1,183 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parse failure pinned to the line and column of the offending input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The text that could not be parsed.
+    pub snippet: String,
+    pub message: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `snippet`, which must be a slice of `input`;
+    /// its line and column are recovered from where it sits in `input`.
+    pub fn at(input: &str, snippet: &str, message: impl Into<String>) -> Self {
+        let offset = offset_of(input, snippet);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: snippet.lines().next().unwrap_or("").to_string(),
+            message: message.into(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Builds an error pointing just past the end of `input`, for input that stops too early.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// Builds an error for the character starting at byte `index` of `line`, a slice of `input`,
+    /// or for the end of the line if `index` is its length.
+    pub fn unexpected_char(input: &str, line: &str, index: usize, expected: &str) -> Self {
+        match line[index..].chars().next() {
+            Some(c) => {
+                let snippet = &line[index..index + c.len_utf8()];
+                Self::at(input, snippet, format!("expected {expected}, found `{c}`"))
+            }
+            None => Self::at(
+                input,
+                &line[index..],
+                format!("expected {expected}, found the end of the line"),
+            ),
+        }
+    }
+
+    /// Re-anchors an error raised while parsing `fragment` on its own so that it
+    /// points into the full `input` the fragment was sliced from.
+    pub fn relative_to(self, input: &str, fragment: &str) -> Self {
+        let base = Self::at(input, fragment, "");
+        let line = base.line + self.line - 1;
+        let column = if self.line == 1 {
+            base.column + self.column - 1
+        } else {
+            self.column
+        };
+
+        Self {
+            line,
+            column,
+            source_line: input.lines().nth(line - 1).unwrap_or("").to_string(),
+            ..self
+        }
+    }
+
+    /// Formats the error with the offending line and a caret under the snippet.
+    pub fn render(&self, source_name: &str) -> String {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let indent: String = self
+            .source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(self.snippet.chars().count().max(1));
+
+        format!(
+            "{}\n{gutter}--> {source_name}:{}:{}\n{gutter} |\n{line_number} | {}\n{gutter} | {indent}{carets}",
+            self.message, self.line, self.column, self.source_line,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `field`, a slice of `input`, describing it as `what` if it is malformed.
+pub fn parse_at<T: FromStr>(input: &str, field: &str, what: &str) -> Result<T, ParseError> {
+    field
+        .parse()
+        .map_err(|_| ParseError::at(input, field, format!("expected {what}, found `{field}`")))
+}
+
+/// Splits `s`, a slice of `input`, around the first `delimiter`.
+pub fn split_once_at<'a>(
+    input: &str,
+    s: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, s, format!("expected `{delimiter}`")))
+}
+
+fn offset_of(input: &str, snippet: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let position = snippet.as_ptr() as usize;
+
+    if position >= start && position + snippet.len() <= start + input.len() {
+        position - start
+    } else {
+        input.find(snippet).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "L68\nR3x\nL5";
+
+    #[test]
+    fn locates_snippet_line_and_column() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = ParseError::at(INPUT, &line[1..], "expected a distance");
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.snippet, "3x");
+    }
+
+    #[test]
+    fn renders_caret_under_snippet() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let error = parse_at::<i32>(INPUT, &line[1..], "a distance").unwrap_err();
+
+        assert_eq!(
+            error.render("test.txt"),
+            "expected a distance, found `3x`\n --> test.txt:2:2\n  |\n2 | R3x\n  |  ^^"
+        );
+    }
+
+    #[test]
+    fn points_past_the_end_of_a_short_line() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let error = ParseError::unexpected_char(INPUT, line, line.len(), "a digit");
+
+        assert_eq!(
+            error.render("test.txt"),
+            "expected a digit, found the end of the line\n --> test.txt:3:3\n  |\n3 | L5\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn re_anchors_fragment_errors() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let error = ParseError::at(line, &line[1..], "bad").relative_to(INPUT, line);
+
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.source_line, "L5");
+    }
+}
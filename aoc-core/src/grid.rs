@@ -0,0 +1,301 @@
+use crate::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Offsets of the four orthogonal neighbours, as `(dx, dy)`.
+pub const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all eight neighbours, including diagonals, as `(dx, dy)`.
+pub const ADJACENT: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A coordinate type that may fall outside a grid, such as a signed offset.
+pub trait Coordinate: Copy {
+    /// The coordinate as an index, or `None` if it cannot be one.
+    fn to_index(self) -> Option<usize>;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            fn to_index(self) -> Option<usize> {
+                usize::try_from(self).ok()
+            }
+        })*
+    };
+}
+
+impl_coordinate!(usize, u8, u16, u32, u64, isize, i8, i16, i32, i64);
+
+/// A rectangular grid stored row by row in a single `Vec`, addressed by `(x, y)`
+/// with `(0, 0)` at the top left.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one cell per character, one row per line, with `f` returning
+    /// `None` for characters that are not valid cells (described by `expected`).
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell =
+                    f(c).ok_or_else(|| ParseError::unexpected_char(input, line, i, expected))?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected a row of {width} cells, found {row_width}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of<C: Coordinate>(&self, x: C, y: C) -> Option<usize> {
+        let (x, y) = (x.to_index()?, y.to_index()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    /// The cell at `(x, y)`, or `None` if that is outside the grid. Accepts
+    /// signed coordinates, so neighbours can be looked up without underflow checks.
+    pub fn get<C: Coordinate>(&self, x: C, y: C) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<C: Coordinate>(&mut self, x: C, y: C) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    pub fn contains<C: Coordinate>(&self, x: C, y: C) -> bool {
+        self.index_of(x, y).is_some()
+    }
+
+    /// Positions of the in-bounds orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    /// Positions of the in-bounds neighbours of `(x, y)`, including diagonals.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &ADJACENT)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx as isize)?;
+            let ny = y.checked_add_signed(dy as isize)?;
+            self.contains(nx, ny).then_some((nx, ny))
+        })
+    }
+
+    /// The position of the first cell, in reading order, matching `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.width, i / self.width), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) is outside a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside a {width}x{height} grid"))
+    }
+}
+
+/// Parses any tile type that can be built from a character.
+impl<T: From<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, "a tile", |c| Some(T::from(c)))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+
+    impl From<char> for Tile {
+        fn from(c: char) -> Self {
+            if c == '#' { Tile::Wall } else { Tile::Open }
+        }
+    }
+
+    const MAZE: &str = "#.#\n...\n##.\n.#.";
+
+    #[test]
+    fn parses_tiles_with_dimensions() {
+        let grid: Grid<Tile> = MAZE.parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.row(2), [Tile::Wall, Tile::Wall, Tile::Open]);
+        assert_eq!(
+            grid.column(1).copied().collect::<Vec<_>>(),
+            [Tile::Open, Tile::Open, Tile::Wall, Tile::Wall]
+        );
+        assert_eq!(grid.find(|&t| t == Tile::Open), Some((1, 0)));
+    }
+
+    #[test]
+    fn bounds_checks_signed_and_unsigned_coordinates() {
+        let grid: Grid<Tile> = MAZE.parse().unwrap();
+
+        assert_eq!(grid.get(2usize, 3usize), Some(&Tile::Open));
+        assert_eq!(grid.get(-1i32, 0), None);
+        assert_eq!(grid.get(0i64, 4), None);
+        assert_eq!(grid[(0, 2)], Tile::Wall);
+    }
+
+    #[test]
+    fn lists_only_in_bounds_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8(0, 0).count(), 3);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours4(2, 1).count(), 3);
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_invalid_cells() {
+        let ragged = "..\n...".parse::<Grid<Tile>>().unwrap_err();
+        assert_eq!(
+            (ragged.line, ragged.message.as_str()),
+            (2, "expected a row of 2 cells, found 3")
+        );
+
+        let invalid = Grid::parse_with("#.\n.x", "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!((invalid.line, invalid.column), (2, 2));
+    }
+}
@@ -0,0 +1,30 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Path that selects stdin instead of a file.
+pub const STDIN: &str = "-";
+
+/// Reads puzzle input from a file, or from stdin when the path is `-`.
+pub fn read_input(path: impl AsRef<Path>) -> io::Result<String> {
+    let path = path.as_ref();
+
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Name to show for `path` in diagnostics.
+pub fn display_name(path: impl AsRef<Path>) -> String {
+    let path = path.as_ref();
+
+    if path == Path::new(STDIN) {
+        "<stdin>".to_string()
+    } else {
+        path.display().to_string()
+    }
+}
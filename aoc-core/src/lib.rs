@@ -0,0 +1,23 @@
+mod answer;
+pub mod answers;
+pub mod batch;
+pub mod disjoint_set;
+mod error;
+mod graph_format;
+pub mod grid;
+mod input;
+mod part;
+pub mod rational;
+mod solution;
+
+pub use answer::Answer;
+pub use answers::Answers;
+pub use batch::{Batch, BatchOptions, Deadline, TimedOut};
+pub use disjoint_set::{RollbackUnionFind, UnionFind};
+pub use error::{ParseError, parse_at, split_once_at};
+pub use graph_format::GraphFormat;
+pub use grid::Grid;
+pub use input::{STDIN, display_name, read_input};
+pub use part::Part;
+pub use rational::{Rational, RationalError};
+pub use solution::Solution;
@@ -0,0 +1,444 @@
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed, ToPrimitive};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+/// Integer types a [`Rational`] can be built on: `i64`, `i128`, and with the
+/// `bigint` feature, `num_bigint::BigInt`.
+pub trait RationalInt:
+    Integer + Signed + Clone + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + ToPrimitive
+{
+}
+
+impl<T> RationalInt for T where
+    T: Integer + Signed + Clone + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + ToPrimitive
+{
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RationalError {
+    DivisionByZero,
+    Overflow,
+    Invalid(String),
+}
+
+impl fmt::Display for RationalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RationalError::DivisionByZero => write!(f, "division by zero"),
+            RationalError::Overflow => write!(f, "arithmetic overflow"),
+            RationalError::Invalid(s) => write!(f, "expected a fraction `a/b`, found `{s}`"),
+        }
+    }
+}
+
+impl Error for RationalError {}
+
+/// An exact fraction, always stored in lowest terms with a positive denominator.
+///
+/// The `checked_*` methods return `None` instead of overflowing; the operators
+/// panic on overflow rather than silently wrapping.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    num: T,
+    den: T,
+}
+
+impl<T: RationalInt> Rational<T> {
+    /// Fails if `den` is zero, or if the fraction's lowest terms do not fit
+    /// in `T`, as with `i64::MIN / -1`.
+    pub fn new(num: T, den: T) -> Result<Self, RationalError> {
+        if den.is_zero() {
+            return Err(RationalError::DivisionByZero);
+        }
+        if num.is_zero() {
+            return Ok(Self::zero());
+        }
+        // The gcd of two equal values can be their absolute value, which
+        // overflows for the most negative integer.
+        if num == den {
+            return Ok(Self::one());
+        }
+
+        let g = num.gcd(&den);
+        let (mut num, mut den) = (num / g.clone(), den / g);
+        if den.is_negative() {
+            let negate = |n: T| T::zero().checked_sub(&n).ok_or(RationalError::Overflow);
+            (num, den) = (negate(num)?, negate(den)?);
+        }
+
+        Ok(Self { num, den })
+    }
+
+    pub fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+
+    pub fn from_integer(n: T) -> Self {
+        Self {
+            num: n,
+            den: T::one(),
+        }
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.num
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.num.is_negative()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den.is_one()
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then(|| self.num.clone())
+    }
+
+    /// The absolute value, or `None` if it does not fit in `T`.
+    pub fn abs(&self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(self.clone())
+        }
+    }
+
+    /// The largest integer not greater than the value.
+    pub fn floor(&self) -> T {
+        self.num.div_floor(&self.den)
+    }
+
+    /// The smallest integer not less than the value. It never overflows:
+    /// a value with a fractional part is below `T`'s largest integer.
+    pub fn ceil(&self) -> T {
+        let floor = self.floor();
+        if self.is_integer() {
+            floor
+        } else {
+            floor + T::one()
+        }
+    }
+
+    pub fn recip(&self) -> Option<Self> {
+        Self::new(self.den.clone(), self.num.clone()).ok()
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        // Adding a whole number to a reduced fraction keeps it reduced.
+        if other.is_integer() {
+            let num = other.num.checked_mul(&self.den)?.checked_add(&self.num)?;
+            return Some(Self {
+                num,
+                den: self.den.clone(),
+            });
+        }
+        if self.is_integer() {
+            return other.checked_add(self);
+        }
+
+        let g = self.den.gcd(&other.den);
+        let (self_scale, other_scale) = (other.den.clone() / g.clone(), self.den.clone() / g);
+
+        let num = self
+            .num
+            .checked_mul(&self_scale)?
+            .checked_add(&other.num.checked_mul(&other_scale)?)?;
+        let den = self.den.checked_mul(&self_scale)?;
+
+        Self::new(num, den).ok()
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        if self.is_zero() || other.is_zero() {
+            return Some(Self::zero());
+        }
+        if other.is_integer() {
+            let g = nonzero(other.num.gcd(&self.den));
+            let num = self.num.checked_mul(&(other.num.clone() / g.clone()))?;
+            return Some(Self {
+                num,
+                den: self.den.clone() / g,
+            });
+        }
+        if self.is_integer() {
+            return other.checked_mul(self);
+        }
+
+        // Cancelling across the product first keeps intermediate values small.
+        let g1 = self.num.gcd(&other.den);
+        let g2 = other.num.gcd(&self.den);
+        let (g1, g2) = (nonzero(g1), nonzero(g2));
+
+        let num = (self.num.clone() / g1.clone()).checked_mul(&(other.num.clone() / g2.clone()))?;
+        let den = (self.den.clone() / g2).checked_mul(&(other.den.clone() / g1))?;
+
+        Self::new(num, den).ok()
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(&other.recip()?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            num: T::zero().checked_sub(&self.num)?,
+            den: self.den.clone(),
+        })
+    }
+}
+
+/// `gcd(0, 0)` is zero; treat it as one so it can be divided out.
+fn nonzero<T: RationalInt>(g: T) -> T {
+    if g.is_zero() { T::one() } else { g }
+}
+
+impl<T: RationalInt> Default for Rational<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: RationalInt> From<T> for Rational<T> {
+    fn from(n: T) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<T: RationalInt> Ord for Rational<T> {
+    /// Compares by continued-fraction expansion, so no products are formed
+    /// and the comparison cannot overflow.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.floor(), other.floor());
+        if a != b {
+            return a.cmp(&b);
+        }
+
+        let self_rem = self.num.mod_floor(&self.den);
+        let other_rem = other.num.mod_floor(&other.den);
+        match (self_rem.is_zero(), other_rem.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => {
+                let self_frac =
+                    Self::new(self.den.clone(), self_rem).expect("remainder is nonzero");
+                let other_frac =
+                    Self::new(other.den.clone(), other_rem).expect("remainder is nonzero");
+                other_frac.cmp(&self_frac)
+            }
+        }
+    }
+}
+
+impl<T: RationalInt> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: RationalInt + fmt::Display> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl<T: RationalInt + FromStr> FromStr for Rational<T> {
+    type Err = RationalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RationalError::Invalid(s.to_string());
+        let parse = |part: &str| part.trim().parse::<T>().map_err(|_| invalid());
+
+        match s.split_once('/') {
+            Some((num, den)) => Self::new(parse(num)?, parse(den)?),
+            None => Ok(Self::from_integer(parse(s)?)),
+        }
+    }
+}
+
+macro_rules! impl_op {
+    ($trait:ident, $method:ident, $checked:ident, $what:literal) => {
+        impl<T: RationalInt> $trait for Rational<T> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.$checked(&rhs).unwrap_or_else(|| {
+                    panic!(concat!("attempt to ", $what, " rationals with overflow"))
+                })
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, checked_add, "add");
+impl_op!(Sub, sub, checked_sub, "subtract");
+impl_op!(Mul, mul, checked_mul, "multiply");
+
+impl<T: RationalInt> Div for Rational<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "attempt to divide a rational by zero");
+        self.checked_div(&rhs)
+            .expect("attempt to divide rationals with overflow")
+    }
+}
+
+impl<T: RationalInt> Neg for Rational<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg()
+            .expect("attempt to negate rational with overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(s: &str) -> Rational<i64> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn normalizes_and_parses() {
+        assert_eq!(r("4/-6"), Rational::new(-2, 3).unwrap());
+        assert_eq!(r("-0/5"), Rational::zero());
+        assert_eq!(r("7"), Rational::from(7));
+        assert_eq!(
+            "1/0".parse::<Rational<i64>>(),
+            Err(RationalError::DivisionByZero)
+        );
+        assert!(matches!(
+            "1/x".parse::<Rational<i64>>(),
+            Err(RationalError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn displays_in_lowest_terms() {
+        assert_eq!(r("6/4").to_string(), "3/2");
+        assert_eq!(r("-8/4").to_string(), "-2");
+    }
+
+    #[test]
+    fn rounds_towards_infinities() {
+        assert_eq!((r("7/2").floor(), r("7/2").ceil()), (3, 4));
+        assert_eq!((r("-7/2").floor(), r("-7/2").ceil()), (-4, -3));
+        assert_eq!((r("5").floor(), r("5").ceil()), (5, 5));
+    }
+
+    #[test]
+    fn does_exact_arithmetic() {
+        assert_eq!(r("1/6") + r("1/3"), r("1/2"));
+        assert_eq!(r("1/6") - r("1/3"), r("-1/6"));
+        assert_eq!(r("2/3") * r("9/4"), r("3/2"));
+        assert_eq!(r("2/3") / r("4/9"), r("3/2"));
+        assert_eq!(-r("2/3"), r("-2/3"));
+        assert_eq!(r("0") * r("1/3"), Rational::zero());
+        assert_eq!(r("1/3") + r("2"), r("7/3"));
+    }
+
+    #[test]
+    fn reports_overflow_instead_of_wrapping() {
+        let big = Rational::new(i64::MAX, 1).unwrap();
+        let third = Rational::new(1, 3).unwrap();
+
+        assert_eq!(big.checked_add(&Rational::one()), None);
+        assert_eq!(big.checked_mul(&r("2")), None);
+        assert_eq!(
+            big.checked_mul(&third).map(|x| x.to_string()),
+            Some("9223372036854775807/3".to_string())
+        );
+        assert_eq!(Rational::from(i64::MIN).checked_neg(), None);
+        assert_eq!(r("0").recip(), None);
+    }
+
+    #[test]
+    fn reports_overflow_at_the_most_negative_integer() {
+        let min = Rational::from(i64::MIN);
+
+        assert_eq!(Rational::new(i64::MIN, -1), Err(RationalError::Overflow));
+        assert_eq!(Rational::new(i64::MIN, i64::MIN), Ok(Rational::one()));
+        assert_eq!(
+            Rational::new(i64::MIN, 2).map(|x| x.to_string()),
+            Ok("-4611686018427387904".to_string())
+        );
+        assert_eq!(min.recip(), None);
+        assert_eq!(Rational::one().checked_div(&min), None);
+        assert_eq!(min.abs(), None);
+        assert_eq!(r("-3/2").abs(), Some(r("3/2")));
+        assert_eq!((min.floor(), min.ceil()), (i64::MIN, i64::MIN));
+        assert_eq!(
+            Rational::new(i64::MIN + 1, 2).unwrap().ceil(),
+            i64::MIN / 2 + 1
+        );
+        assert_eq!(Rational::new(i64::MAX, 2).unwrap().ceil(), i64::MAX / 2 + 1);
+        assert_eq!(
+            "-9223372036854775808/-1".parse::<Rational<i64>>(),
+            Err(RationalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn orders_without_overflow() {
+        let a = Rational::new(i64::MAX - 1, i64::MAX).unwrap();
+        let b = Rational::new(i64::MAX - 2, i64::MAX - 1).unwrap();
+
+        assert!(a > b);
+        assert!(r("-1/2") < r("-1/3"));
+        assert!(r("3") > r("5/2"));
+        let mut values = vec![r("1/2"), r("-3"), r("2/3"), r("0")];
+        values.sort();
+        assert_eq!(values, [r("-3"), r("0"), r("1/2"), r("2/3")]);
+    }
+
+    #[test]
+    fn works_over_wider_integers() {
+        let big = Rational::<i128>::new(i64::MAX as i128, 1).unwrap();
+        assert_eq!(
+            big.checked_mul(&Rational::from(2)).unwrap().to_string(),
+            "18446744073709551614"
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn works_over_bigints() {
+        use num_bigint::BigInt;
+
+        let x: Rational<BigInt> = "340282366920938463463374607431768211457/2".parse().unwrap();
+        assert_eq!(
+            (x.clone() * x).floor().to_string(),
+            "28948022309329048855892746252171976963487637349870610241596083305694166515712"
+        );
+    }
+}
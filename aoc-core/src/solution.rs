@@ -0,0 +1,55 @@
+use crate::{Answer, BatchOptions, GraphFormat, ParseError, Part, read_input};
+
+/// A day's puzzle, split into parsing and the two parts so that each stage
+/// can be run and timed independently.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Like [`part1`](Self::part1), run as `options` say. Only days that
+    /// split their work into a [`Batch`](crate::Batch) need to override it.
+    fn part1_with(input: &Self::Input, _options: &BatchOptions) -> Answer {
+        Self::part1(input)
+    }
+
+    fn part2_with(input: &Self::Input, _options: &BatchOptions) -> Answer {
+        Self::part2(input)
+    }
+
+    /// How the answer to `part` was reached, for `aoc run --explain`. Days
+    /// without an explanation return `None`.
+    fn explain(_input: &Self::Input, _part: Part) -> Option<String> {
+        None
+    }
+
+    /// The input drawn as a graph of what `part` searches, for `aoc run
+    /// --graph`. Days whose input is not a graph return `None`.
+    fn graph(_input: &Self::Input, _part: Part, _format: GraphFormat) -> Option<String> {
+        None
+    }
+
+    fn solve(input: &str, part: Part) -> Result<Answer, ParseError> {
+        Self::solve_with(input, part, &BatchOptions::default())
+    }
+
+    fn solve_with(input: &str, part: Part, options: &BatchOptions) -> Result<Answer, ParseError> {
+        let input = Self::parse(input)?;
+
+        Ok(match part {
+            Part::Part1 => Self::part1_with(&input, options),
+            Part::Part2 => Self::part2_with(&input, options),
+        })
+    }
+
+    /// Solves the puzzle in `file_path`, panicking with a diagnostic if it cannot be read or parsed.
+    fn solve_file(file_path: &str, part: Part) -> Answer {
+        let file_contents = read_input(file_path).expect("Should have been able to read the file");
+
+        Self::solve(&file_contents, part).unwrap_or_else(|e| panic!("{}", e.render(file_path)))
+    }
+}
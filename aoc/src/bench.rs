@@ -0,0 +1,237 @@
+use crate::registry::Day;
+use crate::table;
+use aoc_core::{Answer, ParseError, Part, Solution, display_name, read_input};
+use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How many times to run each stage before and while measuring it.
+#[derive(Clone, Copy)]
+pub struct Config {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    fn label(self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part 1",
+            Stage::Part2 => "part 2",
+        }
+    }
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::Part1 => Stage::Part1,
+            Part::Part2 => Stage::Part2,
+        }
+    }
+}
+
+/// Timing summary for one stage of one day, in nanoseconds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Report {
+    pub warmup: usize,
+    pub runs: usize,
+    pub results: Vec<Measurement>,
+}
+
+/// Times parsing and each part of `S` separately. Both parts reuse the same parsed
+/// input, so only the solver itself is measured; parts returning
+/// [`Answer::Empty`] are left out.
+pub fn measure<S: Solution>(
+    input: &str,
+    config: Config,
+) -> Result<Vec<(Stage, Vec<Duration>)>, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut stages = vec![(
+        Stage::Parse,
+        sample(config, || {
+            black_box(S::parse(black_box(input)).is_ok());
+        }),
+    )];
+
+    for part in Part::ALL {
+        let solve = match part {
+            Part::Part1 => S::part1,
+            Part::Part2 => S::part2,
+        };
+        if solve(&parsed) == Answer::Empty {
+            continue;
+        }
+
+        let samples = sample(config, || {
+            black_box(solve(black_box(&parsed)));
+        });
+        stages.push((part.into(), samples));
+    }
+
+    Ok(stages)
+}
+
+fn sample(config: Config, mut f: impl FnMut()) -> Vec<Duration> {
+    for _ in 0..config.warmup {
+        f();
+    }
+
+    (0..config.runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect()
+}
+
+pub fn summarize(day: u8, stage: Stage, mut samples: Vec<Duration>) -> Measurement {
+    samples.sort();
+    let nanos = |d: Duration| d.as_nanos() as u64;
+    let p95_index = (samples.len() * 95).div_ceil(100).max(1) - 1;
+
+    Measurement {
+        day,
+        stage,
+        runs: samples.len(),
+        min_ns: nanos(samples[0]),
+        median_ns: nanos(samples[samples.len() / 2]),
+        p95_ns: nanos(samples[p95_index]),
+    }
+}
+
+/// Benchmarks every day in `days` against `input`, or each day's own input.txt.
+pub fn run(days: &[&Day], input: Option<&Path>, config: Config) -> Result<Report, String> {
+    let mut results = Vec::new();
+
+    for day in days {
+        let path = input.map_or_else(|| day.input_path(), Path::to_path_buf);
+        let input =
+            read_input(&path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+
+        let stages = day
+            .bench(&input, config)
+            .map_err(|e| e.render(&display_name(&path)))?;
+
+        results.extend(
+            stages
+                .into_iter()
+                .map(|(stage, samples)| summarize(day.number, stage, samples)),
+        );
+    }
+
+    Ok(Report {
+        warmup: config.warmup,
+        runs: config.runs,
+        results,
+    })
+}
+
+/// Formats the report as a table, with the change in median against `baseline` if given.
+pub fn render(report: &Report, baseline: Option<&Report>) -> String {
+    let rows: Vec<[String; 6]> = report
+        .results
+        .iter()
+        .map(|m| {
+            let change = baseline
+                .and_then(|baseline| median_change(m, baseline))
+                .map_or_else(String::new, |change| format!("{change:+.1}%"));
+
+            [
+                m.day.to_string(),
+                m.stage.label().to_string(),
+                format_duration(m.min_ns),
+                format_duration(m.median_ns),
+                format_duration(m.p95_ns),
+                change,
+            ]
+        })
+        .collect();
+
+    table::render(["Day", "Stage", "Min", "Median", "p95", "Change"], &rows)
+}
+
+/// Percentage change in median time relative to the same day and stage in `baseline`.
+pub fn median_change(measurement: &Measurement, baseline: &Report) -> Option<f64> {
+    let before = baseline
+        .results
+        .iter()
+        .find(|b| b.day == measurement.day && b.stage == measurement.stage)?;
+
+    (before.median_ns > 0).then(|| {
+        (measurement.median_ns as f64 - before.median_ns as f64) / before.median_ns as f64 * 100.0
+    })
+}
+
+fn format_duration(nanos: u64) -> String {
+    match nanos {
+        0..1_000 => format!("{nanos} ns"),
+        1_000..1_000_000 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u8, median_ns: u64) -> Measurement {
+        Measurement {
+            day,
+            stage: Stage::Part1,
+            runs: 1,
+            min_ns: median_ns,
+            median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn summarizes_sorted_samples() {
+        let samples = (1..=20).rev().map(Duration::from_nanos).collect();
+        let m = summarize(3, Stage::Parse, samples);
+
+        assert_eq!((m.min_ns, m.median_ns, m.p95_ns), (1, 11, 19));
+        assert_eq!(m.runs, 20);
+    }
+
+    #[test]
+    fn compares_medians_against_baseline() {
+        let baseline = Report {
+            warmup: 0,
+            runs: 1,
+            results: vec![measurement(8, 200)],
+        };
+
+        assert_eq!(median_change(&measurement(8, 250), &baseline), Some(25.0));
+        assert_eq!(median_change(&measurement(9, 250), &baseline), None);
+    }
+
+    #[test]
+    fn formats_durations_in_readable_units() {
+        assert_eq!(format_duration(950), "950 ns");
+        assert_eq!(format_duration(12_340), "12.34 µs");
+        assert_eq!(format_duration(3_500_000_000), "3.50 s");
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use ureq::Agent;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as Advent of Code asks automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/barclayd/advent-of-code-2025 (aoc fetch)";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession,
+    SessionRejected { status: u16 },
+    NotAvailable { year: u16, day: u8 },
+    AlreadyExists(PathBuf),
+    Http { status: u16, url: String },
+    Network { url: String, message: String },
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "no session cookie found: set SESSION_COOKIE in .env or the environment \
+                 to the value of the `session` cookie on adventofcode.com"
+            ),
+            FetchError::SessionRejected { status } => write!(
+                f,
+                "the session cookie was rejected (HTTP {status}); it has probably expired, \
+                 so copy a fresh `session` cookie from adventofcode.com into SESSION_COOKIE"
+            ),
+            FetchError::NotAvailable { year, day } => {
+                write!(f, "day {day} of {year} has not unlocked yet")
+            }
+            FetchError::AlreadyExists(path) => write!(
+                f,
+                "{} already exists; pass --force to overwrite it",
+                path.display()
+            ),
+            FetchError::Http { status, url } => write!(f, "HTTP {status} from {url}"),
+            FetchError::Network { url, message } => write!(f, "could not reach {url}: {message}"),
+            FetchError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Whether a file came from the cache or had to be downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Cache,
+    Download,
+}
+
+/// Downloads puzzle files, keeping a copy of each in `cache_dir` so no file
+/// is ever requested twice.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>, cache_dir: PathBuf) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            cache_dir,
+            agent,
+        }
+    }
+
+    /// The personal puzzle input for `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<(String, Source), FetchError> {
+        self.cached(
+            &format!("{year}/day/{day}/input"),
+            &format!("{year}/day-{day:02}/input.txt"),
+            year,
+            day,
+            |_| true,
+        )
+    }
+
+    /// The puzzle page for `day`. A cached page is only reused once it shows
+    /// both parts, since part 2 is hidden until part 1 has been solved.
+    pub fn page(&self, year: u16, day: u8) -> Result<(String, Source), FetchError> {
+        self.cached(
+            &format!("{year}/day/{day}"),
+            &format!("{year}/day-{day:02}/puzzle.html"),
+            year,
+            day,
+            |page| page.matches("<article").count() >= 2,
+        )
+    }
+
+    fn cached(
+        &self,
+        url_path: &str,
+        cache_key: &str,
+        year: u16,
+        day: u8,
+        is_complete: impl Fn(&str) -> bool,
+    ) -> Result<(String, Source), FetchError> {
+        let cache_path = self.cache_dir.join(cache_key);
+        if let Ok(contents) = fs::read_to_string(&cache_path)
+            && is_complete(&contents)
+        {
+            return Ok((contents, Source::Cache));
+        }
+
+        let contents = self.download(url_path, year, day)?;
+        write_file(&cache_path, &contents)?;
+
+        Ok((contents, Source::Download))
+    }
+
+    fn download(&self, url_path: &str, year: u16, day: u8) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = format!("{}/{url_path}", self.base_url);
+        let network_error = |e: ureq::Error| FetchError::Network {
+            url: url.clone(),
+            message: e.to_string(),
+        };
+
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={session}"))
+            .call()
+            .map_err(network_error)?;
+
+        let status = response.status().as_u16();
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(network_error)?;
+
+        match status {
+            200 => Ok(body),
+            400 | 401 | 403 => Err(FetchError::SessionRejected { status }),
+            404 => Err(FetchError::NotAvailable { year, day }),
+            _ => Err(FetchError::Http { status, url }),
+        }
+    }
+}
+
+/// Writes `contents` to `path`, creating its folder, unless it already exists and `force` is unset.
+pub fn save(path: &Path, contents: &str, force: bool) -> Result<(), FetchError> {
+    if path.exists() && !force {
+        return Err(FetchError::AlreadyExists(path.to_path_buf()));
+    }
+
+    write_file(path, contents)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), FetchError> {
+    let io_error = |source| FetchError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+/// Reads the session cookie from `SESSION_COOKIE`, falling back to the `.env` file in `root`.
+pub fn session_cookie(root: &Path) -> Option<String> {
+    let from_env = std::env::var("SESSION_COOKIE").ok();
+    let from_file = || {
+        let contents = fs::read_to_string(root.join(".env")).ok()?;
+        dotenv_value(&contents, "SESSION_COOKIE")
+    };
+
+    from_env
+        .filter(|value| !value.trim().is_empty())
+        .or_else(from_file)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn dotenv_value(contents: &str, key: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let line = line.trim();
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == key).then(|| value.trim().trim_matches(['"', '\'']).to_string())
+    })
+}
+
+/// Where downloads are cached: `AOC_CACHE_DIR`, else the user's cache folder.
+pub fn default_cache_dir() -> PathBuf {
+    let env_dir = |name| std::env::var_os(name).map(PathBuf::from);
+
+    env_dir("AOC_CACHE_DIR")
+        .or_else(|| env_dir("XDG_CACHE_HOME").map(|dir| dir.join("aoc")))
+        .or_else(|| env_dir("HOME").map(|dir| dir.join(".cache").join("aoc")))
+        .unwrap_or_else(|| std::env::temp_dir().join("aoc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Serves `responses` in order on a local port, recording each request line and cookie.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if line.starts_with("GET") || line.to_lowercase().starts_with("cookie") {
+                        request.push_str(line.trim());
+                        request.push(' ');
+                    }
+                }
+                recorded.lock().unwrap().push(request.trim().to_string());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_reads_from_cache() {
+        let (url, requests) = mock_server(vec![(200, "1,2,3\n")]);
+        let client = Client::new(&url, Some("abc".to_string()), cache_dir("cache"));
+
+        assert_eq!(
+            client.input(2025, 8).unwrap(),
+            ("1,2,3\n".to_string(), Source::Download)
+        );
+        assert_eq!(
+            client.input(2025, 8).unwrap(),
+            ("1,2,3\n".to_string(), Source::Cache)
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /2025/day/8/input HTTP/1.1 cookie: session=abc"]
+        );
+    }
+
+    #[test]
+    fn refreshes_pages_until_part_two_is_cached() {
+        let part_one = "<article>one</article>";
+        let both = "<article>one</article><article>two</article>";
+        let (url, requests) = mock_server(vec![(200, part_one), (200, both)]);
+        let client = Client::new(&url, Some("abc".to_string()), cache_dir("page"));
+
+        assert_eq!(client.page(2025, 3).unwrap().1, Source::Download);
+        assert_eq!(client.page(2025, 3).unwrap().1, Source::Download);
+        assert_eq!(
+            client.page(2025, 3).unwrap(),
+            (both.to_string(), Source::Cache)
+        );
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reports_expired_session() {
+        let (url, _) = mock_server(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        )]);
+        let client = Client::new(&url, Some("stale".to_string()), cache_dir("expired"));
+
+        let error = client.input(2025, 1).unwrap_err();
+        assert!(matches!(error, FetchError::SessionRejected { status: 400 }));
+        assert!(error.to_string().contains("expired"));
+    }
+
+    #[test]
+    fn reports_locked_days() {
+        let (url, _) = mock_server(vec![(404, "Not found")]);
+        let client = Client::new(&url, Some("abc".to_string()), cache_dir("locked"));
+
+        assert!(matches!(
+            client.input(2025, 12).unwrap_err(),
+            FetchError::NotAvailable {
+                year: 2025,
+                day: 12
+            }
+        ));
+    }
+
+    #[test]
+    fn requires_a_session_to_download() {
+        let client = Client::new("http://127.0.0.1:9", None, cache_dir("missing"));
+
+        assert!(matches!(
+            client.input(2025, 1).unwrap_err(),
+            FetchError::MissingSession
+        ));
+    }
+
+    #[test]
+    fn refuses_to_overwrite_without_force() {
+        let dir = cache_dir("save");
+        let path = dir.join("day-01").join("input.txt");
+
+        save(&path, "first", false).unwrap();
+        assert!(matches!(
+            save(&path, "second", false),
+            Err(FetchError::AlreadyExists(_))
+        ));
+        save(&path, "second", true).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+    }
+
+    #[test]
+    fn reads_session_from_dotenv_lines() {
+        let contents = "# comment\nYEAR=2025\nexport SESSION_COOKIE=\"53616c74\"\n";
+        assert_eq!(
+            dotenv_value(contents, "SESSION_COOKIE"),
+            Some("53616c74".to_string())
+        );
+        assert_eq!(dotenv_value(contents, "MISSING"), None);
+    }
+}
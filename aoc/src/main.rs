@@ -0,0 +1,487 @@
+mod bench;
+mod fetch;
+mod puzzle;
+mod record;
+mod registry;
+mod scaffold;
+mod table;
+mod verify;
+
+use aoc_core::{Answer, Answers, BatchOptions, GraphFormat, Part, display_name, read_input};
+use clap::{Args, Parser, Subcommand};
+use registry::Day;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day's solution, or every day with --all
+    Run(RunArgs),
+    /// Check solutions against the answers recorded in answers.toml
+    Verify(VerifyArgs),
+    /// Time parsing and each part, reporting min/median/p95 over repeated runs
+    Bench(BenchArgs),
+    /// Download a day's puzzle input into its folder
+    Fetch(FetchArgs),
+    /// Save a puzzle's examples as test.txt/test2.txt and record their answers
+    Examples(ExamplesArgs),
+    /// Scaffold a new day and add it to the workspace, runner and README
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run (1-12)
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only run this part (1 or 2); runs both when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, or `-` for stdin; defaults to the day's input.txt
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Run every registered day against its input.txt
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Also print how each answer was reached, for days that support it
+    #[arg(long)]
+    explain: bool,
+
+    /// Give up on any one region or machine after this many seconds, for
+    /// days that split their work that way
+    #[arg(long, value_name = "SECONDS")]
+    timeout: Option<f64>,
+
+    /// Count finished regions or machines on stderr as they are solved
+    #[arg(long)]
+    progress: bool,
+
+    /// Print the input as a graph of what the part searches, `dot` or
+    /// `mermaid`, instead of solving it, for days that support it
+    #[arg(long, value_name = "FORMAT", requires = "part", conflicts_with_all = ["all", "explain"])]
+    graph: Option<GraphFormat>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day; verifies every registered day when omitted
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day; benchmarks every registered day when omitted
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Input file, or `-` for stdin; defaults to each day's input.txt
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Untimed runs of each stage before measuring
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Timed runs of each stage
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Print the results as JSON instead of a table
+    #[arg(long)]
+    json: bool,
+
+    /// JSON output of an earlier run to compare median times against
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Fail if any median is this many percent slower than the baseline
+    #[arg(long, requires = "baseline")]
+    max_regression: Option<f64>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to fetch (1-25)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Event year
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Overwrite an existing input.txt
+    #[arg(long)]
+    force: bool,
+
+    /// Site to download from; override to point at a mirror or mock server
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Where downloaded files are cached; defaults to AOC_CACHE_DIR or ~/.cache/aoc
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ExamplesArgs {
+    /// Day whose examples to extract (1-25)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Event year
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Read a saved puzzle page instead of downloading it
+    #[arg(long)]
+    html: Option<PathBuf>,
+
+    /// Overwrite existing example files and recorded answers
+    #[arg(long)]
+    force: bool,
+
+    /// Site to download from; override to point at a mirror or mock server
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Where downloaded files are cached; defaults to AOC_CACHE_DIR or ~/.cache/aoc
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create (1-25)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Event year
+    #[arg(short, long, default_value_t = 2025)]
+    year: u16,
+
+    /// Puzzle title for the README; read from the puzzle page when omitted
+    #[arg(long)]
+    title: Option<String>,
+
+    /// Site to download from; override to point at a mirror or mock server
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// Where downloaded files are cached; defaults to AOC_CACHE_DIR or ~/.cache/aoc
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Examples(args) => examples(args),
+        Command::New(args) => new_day(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let parts = match args.part {
+        Some(n) => vec![Part::try_from(n).expect("clap restricts the range")],
+        None => Part::ALL.to_vec(),
+    };
+
+    let timeout = args
+        .timeout
+        .map(|seconds| {
+            Duration::try_from_secs_f64(seconds)
+                .map_err(|_| format!("invalid timeout: {seconds} seconds"))
+        })
+        .transpose()?;
+    let options = BatchOptions {
+        timeout,
+        progress: args.progress,
+    };
+
+    let day = if args.all { None } else { args.day };
+
+    for day in select_days(day)? {
+        let path = args.input.clone().unwrap_or_else(|| day.input_path());
+        let input =
+            read_input(&path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+
+        if let Some(format) = args.graph {
+            for &part in &parts {
+                let graph = day
+                    .graph(&input, part, format)
+                    .map_err(|e| e.render(&display_name(&path)))?
+                    .ok_or_else(|| format!("day {} has no graph to draw", day.number))?;
+                print!("{graph}");
+            }
+            continue;
+        }
+
+        for &part in &parts {
+            let answer = day
+                .solve_with(&input, part, &options)
+                .map_err(|e| e.render(&display_name(&path)))?;
+
+            match answer {
+                Answer::Empty => {}
+                answer => println!("Day {} {part}: {answer}", day.number),
+            }
+
+            if args.explain {
+                let explanation = day
+                    .explain(&input, part)
+                    .map_err(|e| e.render(&display_name(&path)))?;
+                match explanation {
+                    Some(text) => text.lines().for_each(|line| println!("  {line}")),
+                    None => println!("  (no explanation for day {})", day.number),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<(), String> {
+    let answers = Answers::load()?;
+
+    if verify::verify(&select_days(args.day)?, &answers) {
+        Ok(())
+    } else {
+        Err("some answers did not match".to_string())
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = args
+        .baseline
+        .as_ref()
+        .map(|path| {
+            let contents = fs::read_to_string(path)
+                .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+            serde_json::from_str::<bench::Report>(&contents)
+                .map_err(|e| format!("invalid baseline {}: {e}", path.display()))
+        })
+        .transpose()?;
+
+    let config = bench::Config {
+        warmup: args.warmup,
+        runs: args.runs as usize,
+    };
+    let report = bench::run(&select_days(args.day)?, args.input.as_deref(), config)?;
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&report).expect("reports always serialize");
+        println!("{json}");
+    } else {
+        println!("{}", bench::render(&report, baseline.as_ref()));
+    }
+
+    if let (Some(limit), Some(baseline)) = (args.max_regression, &baseline) {
+        let regressions = report
+            .results
+            .iter()
+            .filter(|m| bench::median_change(m, baseline).is_some_and(|change| change > limit))
+            .count();
+
+        if regressions > 0 {
+            return Err(format!(
+                "{regressions} stage(s) regressed by more than {limit}%"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let path = registry::day_folder(args.day).join("input.txt");
+    if path.exists() && !args.force {
+        return Err(fetch::FetchError::AlreadyExists(path).to_string());
+    }
+
+    let client = fetch::Client::new(
+        &args.base_url,
+        fetch::session_cookie(registry::workspace_root()),
+        args.cache_dir.unwrap_or_else(fetch::default_cache_dir),
+    );
+
+    let (input, source) = client
+        .input(args.year, args.day)
+        .map_err(|e| e.to_string())?;
+    fetch::save(&path, &input, args.force).map_err(|e| e.to_string())?;
+
+    let origin = match source {
+        fetch::Source::Cache => "from cache",
+        fetch::Source::Download => "downloaded",
+    };
+    println!("Wrote {} ({origin})", path.display());
+
+    Ok(())
+}
+
+fn examples(args: ExamplesArgs) -> Result<(), String> {
+    let html = match &args.html {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?,
+        None => {
+            let client = fetch::Client::new(
+                &args.base_url,
+                fetch::session_cookie(registry::workspace_root()),
+                args.cache_dir.unwrap_or_else(fetch::default_cache_dir),
+            );
+            client
+                .page(args.year, args.day)
+                .map_err(|e| e.to_string())?
+                .0
+        }
+    };
+
+    let puzzle = puzzle::Puzzle::from_html(&html);
+    let examples = puzzle.examples();
+    if examples.is_empty() {
+        return Err("no example input found in the puzzle page".to_string());
+    }
+
+    let folder = registry::day_folder(args.day);
+    for (stem, example) in examples {
+        let path = folder.join(format!("{stem}.txt"));
+        match fetch::save(&path, example, args.force) {
+            Ok(()) => println!("Wrote {}", path.display()),
+            Err(fetch::FetchError::AlreadyExists(path)) => {
+                println!(
+                    "Skipped {} (already exists; pass --force to overwrite)",
+                    path.display()
+                )
+            }
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+
+    let answers_path = aoc_core::answers::answers_path();
+    let mut contents = fs::read_to_string(&answers_path).unwrap_or_default();
+    for (part, stem, answer) in puzzle.answers() {
+        let answer = answer
+            .parse()
+            .map_or_else(|_| Answer::from(answer), Answer::Number);
+        match record::record_answer(&contents, args.day, stem, part, &answer, args.force)? {
+            Some(updated) => {
+                contents = updated;
+                println!("Recorded day {} {stem} {part}: {answer}", args.day);
+            }
+            None => println!(
+                "Kept the recorded answer for day {} {stem} {part}",
+                args.day
+            ),
+        }
+    }
+    fs::write(&answers_path, contents)
+        .map_err(|e| format!("could not write {}: {e}", answers_path.display()))?;
+
+    Ok(())
+}
+
+fn new_day(args: NewArgs) -> Result<(), String> {
+    let root = registry::workspace_root();
+    let folder = registry::day_folder(args.day);
+    if folder.exists() {
+        return Err(format!("{} already exists", folder.display()));
+    }
+
+    let title = args.title.clone().unwrap_or_else(|| {
+        let client = fetch::Client::new(
+            &args.base_url,
+            fetch::session_cookie(root),
+            args.cache_dir
+                .clone()
+                .unwrap_or_else(fetch::default_cache_dir),
+        );
+        client
+            .page(args.year, args.day)
+            .ok()
+            .and_then(|(html, _)| puzzle::Puzzle::from_html(&html).title)
+            .unwrap_or_else(|| {
+                eprintln!("warning: could not read the puzzle title; pass --title to set it");
+                format!("Day {}", args.day)
+            })
+    });
+
+    for (file, contents) in scaffold::day_files(args.day) {
+        fetch::save(&folder.join(file), &contents, false).map_err(|e| e.to_string())?;
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+    };
+    let write = |path: &Path, contents: String| {
+        fs::write(path, contents).map_err(|e| format!("could not write {}: {e}", path.display()))
+    };
+    let member = format!("day-{:02}", args.day);
+
+    let manifest = root.join("Cargo.toml");
+    let workspace = scaffold::add_workspace_member(read(&manifest).ok().as_deref(), &member)?;
+    write(&manifest, workspace)?;
+
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    write(
+        &runner_manifest,
+        scaffold::add_runner_dependency(&read(&runner_manifest)?, args.day)?,
+    )?;
+
+    let registry = root.join("aoc").join("src").join("registry.rs");
+    write(
+        &registry,
+        scaffold::add_registry_entry(&read(&registry)?, args.day)?,
+    )?;
+
+    let readme = root.join("README.md");
+    write(
+        &readme,
+        scaffold::update_progress(&read(&readme)?, args.day, &title, args.year)?,
+    )?;
+
+    println!("Created {member} ({title})");
+    println!(
+        "Next: `aoc examples --day {0}` and `aoc fetch --day {0}`",
+        args.day
+    );
+
+    Ok(())
+}
+
+fn select_days(day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    match day {
+        Some(number) => {
+            Ok(vec![registry::find(number).ok_or_else(|| {
+                format!("day {number} is not solved yet")
+            })?])
+        }
+        None => Ok(registry::DAYS.iter().collect()),
+    }
+}
@@ -0,0 +1,198 @@
+//! Pulls examples and answers out of a saved puzzle page, so a new day can
+//! start with its `test.txt` and expected answers already in place.
+
+use aoc_core::Part;
+
+/// What could be extracted from a puzzle page.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: Option<String>,
+    /// One entry per part shown on the page (part 2 only appears once part 1 is solved).
+    pub parts: Vec<PuzzlePart>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PuzzlePart {
+    /// The first `<pre><code>` block, which is where every puzzle puts its example input.
+    pub example: Option<String>,
+    /// The last highlighted `<code><em>` value, which is the example's answer.
+    pub answer: Option<String>,
+}
+
+impl Puzzle {
+    pub fn from_html(html: &str) -> Self {
+        let articles = elements(html, "<article", "</article>");
+
+        let title = articles
+            .first()
+            .and_then(|article| elements(article, "<h2", "</h2>").into_iter().next())
+            .map(|h2| {
+                let text = text_content(h2);
+                let text = text.trim().trim_matches('-').trim();
+                text.split_once(": ")
+                    .map_or(text, |(_, name)| name)
+                    .to_string()
+            });
+
+        let parts = articles
+            .iter()
+            .map(|article| PuzzlePart {
+                example: elements(article, "<pre><code>", "</code></pre>")
+                    .first()
+                    .map(|code| text_content(code)),
+                answer: elements(article, "<code><em>", "</em></code>")
+                    .last()
+                    .map(|em| text_content(em).trim().to_string()),
+            })
+            .collect();
+
+        Self { title, parts }
+    }
+
+    /// Example inputs to save, as `(file stem, contents)`: `test` for the first
+    /// example and `test2` for a second part that brings a different one.
+    pub fn examples(&self) -> Vec<(&'static str, &str)> {
+        let mut examples: Vec<(&'static str, &str)> = Vec::new();
+
+        for (part, stem) in self.parts.iter().zip(["test", "test2"]) {
+            if let Some(example) = &part.example
+                && examples.iter().all(|(_, seen)| seen != example)
+            {
+                examples.push((stem, example));
+            }
+        }
+
+        examples
+    }
+
+    /// The example answers for each part, paired with the example input they belong to.
+    pub fn answers(&self) -> Vec<(Part, &'static str, &str)> {
+        let examples = self.examples();
+
+        self.parts
+            .iter()
+            .zip(Part::ALL)
+            .filter_map(|(puzzle_part, part)| {
+                let answer = puzzle_part.answer.as_deref()?;
+                let stem = match &puzzle_part.example {
+                    Some(example) => examples.iter().find(|(_, e)| e == example)?.0,
+                    None => "test",
+                };
+                Some((part, stem, answer))
+            })
+            .collect()
+    }
+}
+
+/// The inner HTML of every element opened by `open` (up to its `>`) and closed by `close`.
+fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let after_open = &rest[start + open.len()..];
+        let body_start = if open.ends_with('>') {
+            0
+        } else {
+            match after_open.find('>') {
+                Some(i) => i + 1,
+                None => break,
+            }
+        };
+        let body = &after_open[body_start..];
+        let Some(end) = body.find(close) else {
+            break;
+        };
+
+        found.push(&body[..end]);
+        rest = &body[end + close.len()..];
+    }
+
+    found
+}
+
+/// Strips tags and decodes the entities Advent of Code pages use.
+fn text_content(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part::{Part1, Part2};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 11: Reactor ---</h2><p>For example:</p>
+<pre><code>aaa: you hhh
+you: bbb ccc
+</code></pre>
+<p>There are <code>4</code> devices, and <code><em>5</em></code> different paths lead from <code>you</code> to <code>out</code>.</p>
+</article>
+<p>Your puzzle answer was <code>566</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>For example:</p>
+<pre><code>svr: aaa bbb
+<em>fft</em>: ccc &amp; &lt;ddd&gt;
+</code></pre>
+<pre><code>svr,fft,out</code></pre>
+<p>However, only <code><em>2</em></code> paths visit both.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extracts_title_examples_and_answers() {
+        let puzzle = Puzzle::from_html(PAGE);
+
+        assert_eq!(puzzle.title.as_deref(), Some("Reactor"));
+        assert_eq!(
+            puzzle.examples(),
+            [
+                ("test", "aaa: you hhh\nyou: bbb ccc\n"),
+                ("test2", "svr: aaa bbb\nfft: ccc & <ddd>\n"),
+            ]
+        );
+        assert_eq!(
+            puzzle.answers(),
+            [(Part1, "test", "5"), (Part2, "test2", "2")]
+        );
+    }
+
+    #[test]
+    fn reuses_first_example_when_part_two_has_none() {
+        let page = r#"<article><h2>--- Day 3: Lobby ---</h2>
+<pre><code>987654321111111
+811111111111119
+</code></pre><p>Total: <code><em>357</em></code></p></article>
+<article><h2>--- Part Two ---</h2><p>Now <code><em>3121910778619</em></code>.</p></article>"#;
+        let puzzle = Puzzle::from_html(page);
+
+        assert_eq!(puzzle.examples().len(), 1);
+        assert_eq!(
+            puzzle.answers(),
+            [(Part1, "test", "357"), (Part2, "test", "3121910778619")]
+        );
+    }
+
+    #[test]
+    fn handles_pages_without_examples() {
+        let puzzle = Puzzle::from_html("<html><body>Please log in.</body></html>");
+
+        assert_eq!(puzzle, Puzzle::default());
+        assert!(puzzle.examples().is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_core::{Answer, Part};
+use toml_edit::{DocumentMut, Item, Table, value};
+
+/// Adds an answer to the contents of `answers.toml`, keeping its comments and
+/// layout. Returns `None` if an answer is already recorded and `force` is unset.
+pub fn record_answer(
+    contents: &str,
+    day: u8,
+    input: &str,
+    part: Part,
+    answer: &Answer,
+    force: bool,
+) -> Result<Option<String>, String> {
+    let mut document: DocumentMut = contents.parse().map_err(|e| format!("{e}"))?;
+
+    let day_table = document
+        .entry(&format!("day-{day:02}"))
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .ok_or_else(|| format!("`day-{day:02}` is not a table"))?;
+
+    let input_table = day_table
+        .entry(input)
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| format!("`day-{day:02}.{input}` is not a table"))?;
+
+    let key = format!("part{}", part.number());
+    if input_table.contains_key(&key) && !force {
+        return Ok(None);
+    }
+
+    input_table[&key] = match answer {
+        Answer::Number(n) => match i64::try_from(*n) {
+            Ok(n) => value(n),
+            Err(_) => value(n.to_string()),
+        },
+        Answer::Text(s) => value(s.as_str()),
+        Answer::Empty => return Ok(None),
+    };
+
+    Ok(Some(document.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part::{Part1, Part2};
+
+    const ANSWERS: &str = "# Known answers\n\n[day-01.test]\npart1 = 3\n";
+
+    #[test]
+    fn appends_new_tables_and_keeps_comments() {
+        let updated =
+            record_answer(ANSWERS, 13, "test", Part1, &Answer::Number(42), false).unwrap();
+
+        assert_eq!(
+            updated.as_deref(),
+            Some("# Known answers\n\n[day-01.test]\npart1 = 3\n\n[day-13.test]\npart1 = 42\n")
+        );
+    }
+
+    #[test]
+    fn fills_missing_parts_of_existing_tables() {
+        let updated = record_answer(
+            ANSWERS,
+            1,
+            "test",
+            Part2,
+            &Answer::Text("abc".to_string()),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            updated.as_deref(),
+            Some("# Known answers\n\n[day-01.test]\npart1 = 3\npart2 = \"abc\"\n")
+        );
+    }
+
+    #[test]
+    fn keeps_recorded_answers_unless_forced() {
+        let answer = Answer::Number(4);
+
+        assert_eq!(
+            record_answer(ANSWERS, 1, "test", Part1, &answer, false),
+            Ok(None)
+        );
+        assert!(
+            record_answer(ANSWERS, 1, "test", Part1, &answer, true)
+                .unwrap()
+                .unwrap()
+                .contains("part1 = 4")
+        );
+    }
+}
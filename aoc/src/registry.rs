@@ -0,0 +1,106 @@
+use crate::bench::{self, Stage};
+use aoc_core::{Answer, BatchOptions, GraphFormat, ParseError, Part, Solution};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+type Timings = Vec<(Stage, Vec<Duration>)>;
+
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, Part, &BatchOptions) -> Result<Answer, ParseError>,
+    bench: fn(&str, bench::Config) -> Result<Timings, ParseError>,
+    explain: fn(&str, Part) -> Result<Option<String>, ParseError>,
+    graph: fn(&str, Part, GraphFormat) -> Result<Option<String>, ParseError>,
+}
+
+impl Day {
+    const fn of<S: Solution>(number: u8) -> Self {
+        Self {
+            number,
+            solve: S::solve_with,
+            bench: bench::measure::<S>,
+            explain: explain::<S>,
+            graph: graph::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: Part) -> Result<Answer, ParseError> {
+        self.solve_with(input, part, &BatchOptions::default())
+    }
+
+    pub fn solve_with(
+        &self,
+        input: &str,
+        part: Part,
+        options: &BatchOptions,
+    ) -> Result<Answer, ParseError> {
+        (self.solve)(input, part, options)
+    }
+
+    pub fn bench(&self, input: &str, config: bench::Config) -> Result<Timings, ParseError> {
+        (self.bench)(input, config)
+    }
+
+    pub fn explain(&self, input: &str, part: Part) -> Result<Option<String>, ParseError> {
+        (self.explain)(input, part)
+    }
+
+    pub fn graph(
+        &self,
+        input: &str,
+        part: Part,
+        format: GraphFormat,
+    ) -> Result<Option<String>, ParseError> {
+        (self.graph)(input, part, format)
+    }
+
+    pub fn folder(&self) -> PathBuf {
+        day_folder(self.number)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.folder().join("input.txt")
+    }
+}
+
+fn explain<S: Solution>(input: &str, part: Part) -> Result<Option<String>, ParseError> {
+    Ok(S::explain(&S::parse(input)?, part))
+}
+
+fn graph<S: Solution>(
+    input: &str,
+    part: Part,
+    format: GraphFormat,
+) -> Result<Option<String>, ParseError> {
+    Ok(S::graph(&S::parse(input)?, part, format))
+}
+
+pub static DAYS: &[Day] = &[
+    Day::of::<day_01::Day01>(1),
+    Day::of::<day_02::Day02>(2),
+    Day::of::<day_03::Day03>(3),
+    Day::of::<day_04::Day04>(4),
+    Day::of::<day_05::Day05>(5),
+    Day::of::<day_06::Day06>(6),
+    Day::of::<day_07::Day07>(7),
+    Day::of::<day_08::Day08>(8),
+    Day::of::<day_09::Day09>(9),
+    Day::of::<day_10::Day10>(10),
+    Day::of::<day_11::Day11>(11),
+    Day::of::<day_12::Day12>(12),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// The `day-NN` folder for `number`, whether or not that day exists yet.
+pub fn day_folder(number: u8) -> PathBuf {
+    workspace_root().join(format!("day-{number:02}"))
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+}
@@ -0,0 +1,276 @@
+//! Generates a new day's crate and wires it into the workspace, the `aoc`
+//! registry and the README progress table.
+
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table, Value, value};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day/main.rs.template");
+
+/// The files of a new day's crate, as paths relative to its folder.
+pub fn day_files(day: u8) -> [(&'static str, String); 4] {
+    let render = |template: &str| {
+        template
+            .replace("{{day_padded}}", &format!("{day:02}"))
+            .replace("{{day}}", &day.to_string())
+    };
+
+    [
+        ("Cargo.toml", render(CARGO_TEMPLATE)),
+        ("src/lib.rs", render(LIB_TEMPLATE)),
+        ("src/main.rs", render(MAIN_TEMPLATE)),
+        ("test.txt", String::new()),
+    ]
+}
+
+/// Adds `member` to the root manifest's workspace members, keeping them sorted
+/// one per line, and creates the workspace if there is none yet.
+pub fn add_workspace_member(manifest: Option<&str>, member: &str) -> Result<String, String> {
+    let mut document: DocumentMut = manifest
+        .unwrap_or_default()
+        .parse()
+        .map_err(|e| format!("{e}"))?;
+
+    let workspace = document
+        .entry("workspace")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table["resolver"] = value("3");
+            Item::Table(table)
+        })
+        .as_table_mut()
+        .ok_or("`workspace` is not a table")?;
+
+    let mut members: Vec<String> = workspace
+        .get("members")
+        .and_then(Item::as_array)
+        .map(|array| {
+            array
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if !members.iter().any(|m| m == member) {
+        members.push(member.to_string());
+    }
+    members.sort();
+
+    let mut array: Array = members.iter().map(String::as_str).collect();
+    for item in array.iter_mut() {
+        item.decor_mut().set_prefix("\n    ");
+    }
+    array.set_trailing_comma(true);
+    array.set_trailing("\n");
+    workspace["members"] = value(array);
+
+    Ok(document.to_string())
+}
+
+/// Adds a path dependency on the new day to the `aoc` crate's manifest.
+pub fn add_runner_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let mut document: DocumentMut = manifest.parse().map_err(|e| format!("{e}"))?;
+    let dependencies = document["dependencies"]
+        .as_table_mut()
+        .ok_or("`dependencies` is not a table")?;
+
+    let mut dependency = InlineTable::new();
+    dependency.insert("path", Value::from(format!("../day-{day:02}")));
+    dependencies.insert(&format!("day-{day:02}"), value(dependency));
+    dependencies.sort_values();
+
+    Ok(document.to_string())
+}
+
+/// Registers the new day in `registry.rs`, after the last day before it.
+pub fn add_registry_entry(registry: &str, day: u8) -> Result<String, String> {
+    let entry = |n: u8| format!("    Day::of::<day_{n:02}::Day{n:02}>({n}),");
+    if registry.lines().any(|line| line == entry(day)) {
+        return Ok(registry.to_string());
+    }
+
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))
+        .ok_or("could not find `DAYS` in the registry")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .ok_or("could not find the end of `DAYS` in the registry")?;
+
+    let insert_at = (start + 1..end)
+        .find(|&i| entry_day(lines[i]).is_some_and(|n| n > day))
+        .unwrap_or(end);
+
+    let new_entry = entry(day);
+    lines.insert(insert_at, &new_entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn entry_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("Day::of::<")?
+        .split_once(">(")?
+        .1
+        .strip_suffix("),")?
+        .parse()
+        .ok()
+}
+
+/// Adds a row for `day` to the README's progress table if it has none,
+/// re-aligning the columns and recomputing the star total in the heading.
+pub fn update_progress(readme: &str, day: u8, title: &str, year: u16) -> Result<String, String> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let heading = lines
+        .iter()
+        .position(|line| line.starts_with("## Progress"))
+        .ok_or("could not find the `## Progress` heading in the README")?;
+    let table_start = (heading + 1..lines.len())
+        .find(|&i| lines[i].starts_with('|'))
+        .ok_or("could not find the progress table in the README")?;
+    let table_end = (table_start..lines.len())
+        .find(|&i| !lines[i].starts_with('|'))
+        .unwrap_or(lines.len());
+
+    let cells = |line: &str| -> Vec<String> {
+        line.trim()
+            .trim_matches('|')
+            .split('|')
+            .map(|cell| cell.trim().to_string())
+            .collect()
+    };
+
+    let header = cells(lines[table_start]);
+    let mut rows: Vec<Vec<String>> = lines[table_start + 2..table_end]
+        .iter()
+        .map(|line| cells(line))
+        .collect();
+
+    if !rows.iter().any(|row| row[0] == day.to_string()) {
+        rows.push(vec![
+            day.to_string(),
+            format!("[{title}](https://adventofcode.com/{year}/day/{day})"),
+            String::new(),
+        ]);
+        rows.sort_by_key(|row| row[0].parse::<u8>().unwrap_or(u8::MAX));
+    }
+
+    let stars: usize = rows.iter().map(|row| row[2].matches('⭐').count()).sum();
+    let total = rows.len() * 2;
+
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render_row = |row: &[String]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| {
+                let padding = width - cell.chars().count();
+                format!(" {cell}{} ", " ".repeat(padding))
+            })
+            .collect();
+        format!("|{}|", cells.join("|"))
+    };
+    let separator = widths
+        .iter()
+        .map(|&width| "-".repeat(width + 2))
+        .collect::<Vec<_>>()
+        .join("|");
+
+    let mut output: Vec<String> = lines[..heading].iter().map(|l| l.to_string()).collect();
+    output.push(format!("## Progress ({stars}/{total} ⭐️)"));
+    output.extend(
+        lines[heading + 1..table_start]
+            .iter()
+            .map(|l| l.to_string()),
+    );
+    output.push(render_row(&header));
+    output.push(format!("|{separator}|"));
+    output.extend(rows.iter().map(|row| render_row(row)));
+    output.extend(lines[table_end..].iter().map(|l| l.to_string()));
+
+    Ok(output.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# AoC\n\n## Progress (3/4 ⭐️)\n\n| Day | Challenge                                    | Stars |\n|-----|----------------------------------------------|-------|\n| 1   | [Lobby](https://adventofcode.com/2025/day/1) | ⭐️⭐️  |\n| 2   | [Gift](https://adventofcode.com/2025/day/2)  | ⭐     |\n\nFooter\n";
+
+    #[test]
+    fn renders_templates_without_placeholders() {
+        let files = day_files(4);
+
+        assert!(files[0].1.contains("name = \"day-04\""));
+        assert!(files[1].1.contains("impl Solution for Day04"));
+        assert!(files[1].1.contains("expected(4, \"test\", Part1)"));
+        assert!(files[2].1.contains("use day_04::Day04;"));
+        assert!(files.iter().all(|(_, contents)| !contents.contains("{{")));
+    }
+
+    #[test]
+    fn keeps_existing_rows_and_totals_unchanged() {
+        assert_eq!(update_progress(README, 1, "Lobby", 2025).unwrap(), README);
+    }
+
+    #[test]
+    fn adds_row_and_realigns_table() {
+        let updated = update_progress(README, 10, "Factory Floor Extended", 2025).unwrap();
+
+        assert_eq!(
+            updated,
+            "# AoC\n\n## Progress (3/6 ⭐️)\n\n| Day | Challenge                                                      | Stars |\n|-----|----------------------------------------------------------------|-------|\n| 1   | [Lobby](https://adventofcode.com/2025/day/1)                   | ⭐️⭐️  |\n| 2   | [Gift](https://adventofcode.com/2025/day/2)                    | ⭐     |\n| 10  | [Factory Floor Extended](https://adventofcode.com/2025/day/10) |       |\n\nFooter\n"
+        );
+    }
+
+    #[test]
+    fn adds_sorted_workspace_members() {
+        let manifest =
+            "[workspace]\nresolver = \"3\"\nmembers = [\n    \"aoc\",\n    \"day-02\",\n]\n";
+
+        assert_eq!(
+            add_workspace_member(Some(manifest), "day-01").unwrap(),
+            "[workspace]\nresolver = \"3\"\nmembers = [\n    \"aoc\",\n    \"day-01\",\n    \"day-02\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn creates_missing_workspace() {
+        assert_eq!(
+            add_workspace_member(None, "day-01").unwrap(),
+            "[workspace]\nresolver = \"3\"\nmembers = [\n    \"day-01\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let registry = "pub static DAYS: &[Day] = &[\n    Day::of::<day_01::Day01>(1),\n    Day::of::<day_03::Day03>(3),\n];\n";
+
+        let updated = add_registry_entry(registry, 2).unwrap();
+        assert_eq!(
+            updated,
+            "pub static DAYS: &[Day] = &[\n    Day::of::<day_01::Day01>(1),\n    Day::of::<day_02::Day02>(2),\n    Day::of::<day_03::Day03>(3),\n];\n"
+        );
+        assert_eq!(add_registry_entry(&updated, 2).unwrap(), updated);
+    }
+
+    #[test]
+    fn adds_runner_dependency_in_order() {
+        let manifest = "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nday-01 = { path = \"../day-01\" }\nserde = \"1\"\n";
+
+        assert_eq!(
+            add_runner_dependency(manifest, 2).unwrap(),
+            "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nday-01 = { path = \"../day-01\" }\nday-02 = { path = \"../day-02\" }\nserde = \"1\"\n"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+/// Renders rows as left-aligned columns separated by two spaces, with a header row.
+pub fn render<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(header.to_vec())];
+    lines.extend(
+        rows.iter()
+            .map(|row| format_row(row.iter().map(String::as_str).collect())),
+    );
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pads_columns_to_widest_cell() {
+        let rows = [
+            ["1".to_string(), "PASS".to_string()],
+            ["12".to_string(), "FAIL".to_string()],
+        ];
+
+        assert_eq!(
+            render(["Day", "Status"], &rows),
+            "Day  Status\n1    PASS\n12   FAIL"
+        );
+    }
+}
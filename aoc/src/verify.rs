@@ -0,0 +1,93 @@
+use crate::registry::Day;
+use crate::table;
+use aoc_core::{Answer, Answers, Part, read_input};
+use std::collections::BTreeSet;
+use std::fs;
+
+enum Status {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+/// Checks every day's inputs against `answers.toml`, printing one row per
+/// input and part. Returns whether every check passed.
+pub fn verify(days: &[&Day], answers: &Answers) -> bool {
+    let mut rows = Vec::new();
+    let mut failed = false;
+
+    for day in days {
+        for input_name in input_names(day, answers) {
+            let path = day.folder().join(format!("{input_name}.txt"));
+            let input = read_input(&path).ok();
+            let recorded = Part::ALL
+                .iter()
+                .any(|&part| answers.get(day.number, &input_name, part).is_some());
+
+            for part in Part::ALL {
+                let expected = answers.get(day.number, &input_name, part);
+                // Inputs that only apply to one part (like day 11's test2) record just that part.
+                if expected.is_none() && recorded {
+                    continue;
+                }
+
+                let status = match (&input, expected) {
+                    (None, _) => Status::Missing(format!("{} not found", path.display())),
+                    (Some(input), expected) => match (day.solve(input, part), expected) {
+                        (Ok(Answer::Empty), _) => continue,
+                        (Err(e), _) => Status::Fail(format!("parse error: {e}")),
+                        (Ok(got), None) => {
+                            Status::Missing(format!("got {got}, no answer recorded"))
+                        }
+                        (Ok(got), Some(expected)) if got == *expected => Status::Pass,
+                        (Ok(got), Some(expected)) => {
+                            Status::Fail(format!("got {got}, expected {expected}"))
+                        }
+                    },
+                };
+
+                let (label, detail) = match status {
+                    Status::Pass => ("PASS", String::new()),
+                    Status::Fail(detail) => {
+                        failed = true;
+                        ("FAIL", detail)
+                    }
+                    Status::Missing(detail) => ("MISSING", detail),
+                };
+
+                rows.push([
+                    day.number.to_string(),
+                    input_name.clone(),
+                    part.number().to_string(),
+                    label.to_string(),
+                    detail,
+                ]);
+            }
+        }
+    }
+
+    println!(
+        "{}",
+        table::render(["Day", "Input", "Part", "Status", "Detail"], &rows)
+    );
+
+    !failed
+}
+
+/// The day's `input` plus every input with a recorded answer or a `.txt` file in its folder.
+fn input_names(day: &Day, answers: &Answers) -> BTreeSet<String> {
+    let mut names: BTreeSet<String> = answers.inputs(day.number).map(String::from).collect();
+    names.insert("input".to_string());
+
+    if let Ok(entries) = fs::read_dir(day.folder()) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().is_some_and(|ext| ext == "txt")
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            {
+                names.insert(stem.to_string());
+            }
+        }
+    }
+
+    names
+}
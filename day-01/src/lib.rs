@@ -0,0 +1,138 @@
+use aoc_core::{Answer, ParseError, Solution, parse_at};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left(i32),
+    Right(i32),
+}
+
+fn rotate_dial(starting_position: i32, direction: Direction) -> i32 {
+    match direction {
+        Direction::Left(x) => (starting_position - x).rem_euclid(100),
+        Direction::Right(x) => (starting_position + x).rem_euclid(100),
+    }
+}
+
+fn rotate_dial_with_wrappings(starting_position: i32, direction: Direction) -> (i32, i32) {
+    let offset = match direction {
+        Direction::Left(x) => starting_position - x,
+        Direction::Right(x) => starting_position + x,
+    };
+
+    (offset.rem_euclid(100), offset.div_euclid(100).abs())
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Direction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some(turn) = line.chars().next() else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected a rotation such as `L68`",
+                ));
+            };
+            let (turn_str, distance) = line.split_at(turn.len_utf8());
+            let num = parse_at(input, distance, "a distance")?;
+            match turn {
+                'L' => Ok(Direction::Left(num)),
+                'R' => Ok(Direction::Right(num)),
+                _ => Err(ParseError::at(input, turn_str, "expected `L` or `R`")),
+            }
+        })
+        .collect()
+}
+
+fn count_with_strategy<F>(instructions: &[Direction], mut counter: F) -> i32
+where
+    F: FnMut(i32, Direction) -> (i32, i32),
+{
+    let mut position = 50;
+    let mut count = 0;
+
+    for &instruction in instructions {
+        let (new_position, increment) = counter(position, instruction);
+        count += increment;
+        position = new_position;
+    }
+
+    count
+}
+
+pub fn get_count_of_rotations_completed_at_0(instructions: &[Direction]) -> i32 {
+    count_with_strategy(instructions, |position, instruction| {
+        let new_position = rotate_dial(position, instruction);
+        let increment = if new_position == 0 { 1 } else { 0 };
+        (new_position, increment)
+    })
+}
+
+pub fn get_count_of_rotations_past_0(instructions: &[Direction]) -> i32 {
+    count_with_strategy(instructions, |position, instruction| {
+        let (new_position, mut wrappings) = rotate_dial_with_wrappings(position, instruction);
+
+        if position == 0 && matches!(instruction, Direction::Left(_)) {
+            wrappings = wrappings.saturating_sub(1);
+        }
+
+        if let Direction::Left(x) = instruction
+            && new_position == 0
+            && x > 0
+        {
+            wrappings += 1;
+        }
+
+        (new_position, wrappings)
+    })
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        get_count_of_rotations_completed_at_0(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        get_count_of_rotations_past_0(instructions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_count_of_rotations_completed_at_0_for_test_data() {
+        let count = Day01::solve_file("./test.txt", Part1);
+        assert_eq!(count, expected(1, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_count_of_rotations_completed_at_0_for_input_data() {
+        let count = Day01::solve_file("./input.txt", Part1);
+        assert_eq!(count, expected(1, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_count_of_wrappings_past_0_for_test_data() {
+        let count = Day01::solve_file("./test.txt", Part2);
+        assert_eq!(count, expected(1, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_count_of_wrappings_past_0_for_input_data() {
+        let count = Day01::solve_file("./input.txt", Part2);
+        assert_eq!(count, expected(1, "input", Part2));
+    }
+}
@@ -0,0 +1,22 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use day_02::Range;
+use std::hint::black_box;
+
+fn sum_multi_invalid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_multi_invalid");
+
+    for len in [1_000, 10_000, 100_000] {
+        let start = 1_000_000_000;
+        let range: Range = format!("{start}-{}", start + len - 1).parse().unwrap();
+
+        group.throughput(Throughput::Elements(len));
+        group.bench_with_input(BenchmarkId::from_parameter(len), &range, |b, range| {
+            b.iter(|| black_box(range).sum_multi_invalid())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, sum_multi_invalid);
+criterion_main!(benches);
@@ -0,0 +1,130 @@
+use aoc_core::{Answer, ParseError, Solution, parse_at, split_once_at};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Range {
+    start: usize,
+    end: usize,
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = split_once_at(s, s, "-")?;
+        Ok(Range {
+            start: parse_at(s, left, "a range start")?,
+            end: parse_at(s, right, "a range end")?,
+        })
+    }
+}
+
+impl Range {
+    pub fn sum_invalid(&self) -> usize {
+        (self.start..=self.end)
+            .filter(|num| {
+                let half_digits = num.ilog10().div_ceil(2);
+                let mod_val = 10usize.pow(half_digits);
+                let lower_half = num % mod_val;
+                let upper_half = num / mod_val;
+                lower_half == upper_half
+            })
+            .sum()
+    }
+
+    pub fn sum_multi_invalid(&self) -> usize {
+        let mut invalid_sum = 0;
+
+        for num in self.start..=self.end {
+            let half_digits = num.ilog10().div_ceil(2);
+            for digit_count in 1..=half_digits {
+                let mod_val = 10usize.pow(digit_count);
+                let last_n_digits = num % mod_val;
+                let mut test_num = num / mod_val;
+                if last_n_digits == 0 || last_n_digits.ilog(10) + 1 != digit_count {
+                    continue;
+                }
+                let mut found = true;
+                while test_num > 0 {
+                    found = test_num % mod_val == last_n_digits;
+                    if !found {
+                        break;
+                    }
+                    test_num /= mod_val;
+                }
+
+                if found {
+                    invalid_sum += num;
+                    break;
+                }
+            }
+        }
+
+        invalid_sum
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::at_end(input, "expected a list of ranges"))?;
+
+        line.split(',')
+            .map(|range| {
+                range
+                    .parse()
+                    .map_err(|e: ParseError| e.relative_to(input, range))
+            })
+            .collect()
+    }
+
+    fn part1(ranges: &Self::Input) -> Answer {
+        ranges.iter().map(Range::sum_invalid).sum::<usize>().into()
+    }
+
+    fn part2(ranges: &Self::Input) -> Answer {
+        ranges
+            .iter()
+            .map(Range::sum_multi_invalid)
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day02::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(2, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day02::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(2, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day02::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(2, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day02::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(2, "input", Part2));
+    }
+}
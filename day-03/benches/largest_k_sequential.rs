@@ -0,0 +1,34 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use day_03::largest_k_sequential;
+use std::hint::black_box;
+
+/// Pseudo-random battery ratings from 1 to 9, stable across runs.
+fn bank(len: usize) -> Vec<i64> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            (state >> 33) as i64 % 9 + 1
+        })
+        .collect()
+}
+
+fn largest_k(c: &mut Criterion) {
+    let mut group = c.benchmark_group("largest_k_sequential");
+
+    for len in [100, 1_000, 10_000] {
+        let digits = bank(len);
+
+        group.throughput(Throughput::Elements(len as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(len), &digits, |b, digits| {
+            b.iter(|| largest_k_sequential(black_box(digits), 12))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, largest_k);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+use aoc_core::{Answer, Batch, BatchOptions, ParseError, Solution};
+
+pub fn two_largest_sequential(nums: &[i64]) -> i64 {
+    let mut max_after = i64::MIN;
+    let mut best = i64::MIN;
+
+    for i in (0..nums.len() - 1).rev() {
+        max_after = max_after.max(nums[i + 1]);
+        let combined = nums[i] * 10 + max_after;
+        best = best.max(combined);
+    }
+
+    best
+}
+
+pub fn largest_k_sequential(nums: &[i64], k: usize) -> i64 {
+    let n = nums.len();
+    if n < k {
+        return 0;
+    }
+
+    let mut result: i64 = 0;
+    let mut start: usize = 0;
+
+    for remaining in (1..=k).rev() {
+        let end = n - remaining;
+
+        let mut max_val = i64::MIN;
+        let mut max_idx = start;
+        for (i, &num) in nums.iter().enumerate().take(end + 1).skip(start) {
+            if num > max_val {
+                max_val = num;
+                max_idx = i;
+            }
+        }
+
+        result = result * 10 + max_val;
+        start = max_idx + 1;
+    }
+
+    result
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                if line.len() < 2 {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "expected at least two batteries",
+                    ));
+                }
+
+                line.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10)
+                            .map(|d| d as i64)
+                            .ok_or_else(|| ParseError::unexpected_char(input, line, i, "a digit"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Answer {
+        Self::part1_with(banks, &BatchOptions::default())
+    }
+
+    fn part2(banks: &Self::Input) -> Answer {
+        Self::part2_with(banks, &BatchOptions::default())
+    }
+
+    fn part1_with(banks: &Self::Input, options: &BatchOptions) -> Answer {
+        Batch::with_options("banks", *options)
+            .map(banks, |digits| two_largest_sequential(digits))
+            .into_iter()
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part2_with(banks: &Self::Input, options: &BatchOptions) -> Answer {
+        Batch::with_options("banks", *options)
+            .map(banks, |digits| largest_k_sequential(digits, 12))
+            .into_iter()
+            .sum::<i64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day03::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(3, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day03::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(3, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day03::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(3, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day03::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(3, "input", Part2));
+    }
+}
@@ -0,0 +1,50 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use day_04::PrintingDepartment;
+
+/// A square grid with roughly two thirds of its cells holding rolls, stable across runs.
+fn grid(size: usize) -> String {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6_364_136_223_846_793_005)
+                        .wrapping_add(1);
+                    if (state >> 33).is_multiple_of(3) {
+                        '.'
+                    } else {
+                        '@'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn count_total_removable_rolls(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count_total_removable_rolls");
+
+    for size in [50, 100, 200] {
+        let department = PrintingDepartment::new(&grid(size)).unwrap();
+
+        group.throughput(Throughput::Elements((size * size) as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{size}x{size}")),
+            &department,
+            |b, department| {
+                b.iter_batched(
+                    || department.clone(),
+                    |mut department| department.count_total_removable_rolls(),
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, count_total_removable_rolls);
+criterion_main!(benches);
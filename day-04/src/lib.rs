@@ -0,0 +1,135 @@
+use aoc_core::{Answer, Grid, ParseError, Solution};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    RollOfPaper,
+    Empty,
+}
+
+impl From<char> for Tile {
+    fn from(c: char) -> Self {
+        match c {
+            '@' => Tile::RollOfPaper,
+            _ => Tile::Empty,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PrintingDepartment {
+    grid: Grid<Tile>,
+}
+
+impl PrintingDepartment {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(input, "`@` or `.`", |c| {
+            matches!(c, '@' | '.').then(|| Tile::from(c))
+        })?;
+        Ok(Self { grid })
+    }
+
+    fn count_adjacent_rolls(&self, x: usize, y: usize) -> usize {
+        self.grid
+            .neighbours8(x, y)
+            .filter(|&position| self.grid[position] == Tile::RollOfPaper)
+            .count()
+    }
+
+    fn is_accessible(&self, x: usize, y: usize) -> bool {
+        self.grid[(x, y)] == Tile::RollOfPaper && self.count_adjacent_rolls(x, y) < 4
+    }
+
+    pub fn count_accessible_rolls(&self) -> usize {
+        self.grid
+            .positions()
+            .filter(|&(x, y)| self.is_accessible(x, y))
+            .count()
+    }
+
+    pub fn count_total_removable_rolls(&mut self) -> usize {
+        let mut count = 0;
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+        let mut in_queue = Grid::new(self.grid.width(), self.grid.height(), false);
+
+        for (x, y) in self.grid.positions() {
+            if self.is_accessible(x, y) {
+                queue.push_back((x, y));
+                in_queue[(x, y)] = true;
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            in_queue[(x, y)] = false;
+
+            if self.grid[(x, y)] != Tile::RollOfPaper {
+                continue;
+            }
+
+            self.grid[(x, y)] = Tile::Empty;
+            count += 1;
+
+            for (nx, ny) in self.grid.neighbours8(x, y) {
+                if self.is_accessible(nx, ny) && !in_queue[(nx, ny)] {
+                    queue.push_back((nx, ny));
+                    in_queue[(nx, ny)] = true;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = PrintingDepartment;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        PrintingDepartment::new(input)
+    }
+
+    fn part1(printing_department: &Self::Input) -> Answer {
+        printing_department.count_accessible_rolls().into()
+    }
+
+    fn part2(printing_department: &Self::Input) -> Answer {
+        printing_department
+            .clone()
+            .count_total_removable_rolls()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day04;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day04::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(4, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day04::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(4, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day04::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(4, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day04::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(4, "input", Part2));
+    }
+}
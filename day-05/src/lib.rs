@@ -0,0 +1,102 @@
+use aoc_core::{Answer, ParseError, Solution, parse_at, split_once_at};
+use rangemap::RangeInclusiveSet;
+
+pub struct Inventory {
+    fresh_ingredient_id_ranges: RangeInclusiveSet<i64>,
+    available_ingredient_ids: Vec<i64>,
+}
+
+impl Inventory {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (ranges, ids) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::at_end(input, "expected a blank line before the ingredient IDs")
+        })?;
+
+        let fresh_ingredient_id_ranges: RangeInclusiveSet<i64> = ranges
+            .lines()
+            .map(|line| {
+                let (start, end) = split_once_at(input, line, "-")?;
+                let start: i64 = parse_at(input, start, "a range start")?;
+                let end: i64 = parse_at(input, end, "a range end")?;
+                if start > end {
+                    return Err(ParseError::at(input, line, "range starts after it ends"));
+                }
+                Ok(start..=end)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let available_ingredient_ids: Vec<i64> = ids
+            .lines()
+            .map(|n| parse_at(input, n, "an ingredient ID"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            fresh_ingredient_id_ranges,
+            available_ingredient_ids,
+        })
+    }
+
+    pub fn count_fresh_available(&self) -> usize {
+        self.available_ingredient_ids
+            .iter()
+            .filter(|n| self.fresh_ingredient_id_ranges.contains(n))
+            .count()
+    }
+
+    pub fn count_fresh_ids(&self) -> i64 {
+        self.fresh_ingredient_id_ranges
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Inventory::new(input)
+    }
+
+    fn part1(inventory: &Self::Input) -> Answer {
+        inventory.count_fresh_available().into()
+    }
+
+    fn part2(inventory: &Self::Input) -> Answer {
+        inventory.count_fresh_ids().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day05::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(5, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day05::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(5, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day05::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(5, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day05::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(5, "input", Part2));
+    }
+}
@@ -0,0 +1,231 @@
+use aoc_core::{Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(Operator::Add),
+            '*' => Some(Operator::Multiply),
+            _ => None,
+        }
+    }
+
+    fn apply(self, numbers: &[i64]) -> i64 {
+        match self {
+            Operator::Add => numbers.iter().sum(),
+            Operator::Multiply => numbers.iter().product(),
+        }
+    }
+}
+
+fn parse_grid(lines: &[String]) -> Vec<Vec<char>> {
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|l| {
+            let mut chars: Vec<char> = l.chars().collect();
+            chars.resize(width, ' ');
+            chars
+        })
+        .collect()
+}
+
+fn read_column(grid: &[Vec<char>], col: usize) -> (String, Option<Operator>) {
+    let mut digits = String::new();
+    let mut operator = None;
+
+    for row in grid {
+        let c = row[col];
+        match Operator::from_char(c) {
+            Some(op) => operator = Some(op),
+            None => digits.push(c),
+        }
+    }
+
+    (digits, operator)
+}
+
+pub fn solve_part1(lines: &[String]) -> i64 {
+    let Some((operator_line, number_lines)) = lines.split_last() else {
+        return 0;
+    };
+
+    let operators: Vec<Operator> = operator_line
+        .split_whitespace()
+        .filter_map(|s| s.chars().next().and_then(Operator::from_char))
+        .collect();
+
+    let mut columns: Vec<Vec<i64>> = vec![Vec::new(); operators.len()];
+
+    for line in number_lines {
+        let numbers = line.split_whitespace().filter_map(|s| s.parse().ok());
+        for (column, num) in columns.iter_mut().zip(numbers) {
+            column.push(num);
+        }
+    }
+
+    columns
+        .iter()
+        .zip(&operators)
+        .map(|(col, op)| op.apply(col))
+        .sum()
+}
+
+pub fn solve_part2(lines: &[String]) -> i64 {
+    let grid = parse_grid(lines);
+    let width = grid.first().map_or(0, |row| row.len());
+
+    let mut answers = Vec::new();
+    let mut equation = Vec::new();
+    let mut current_op = None;
+
+    for col in 0..width {
+        let (digits, operator) = read_column(&grid, col);
+
+        if let Some(op) = operator {
+            current_op = Some(op);
+        }
+
+        match digits.trim().parse::<i64>() {
+            Ok(num) => equation.push(num),
+            Err(_) if !equation.is_empty() => {
+                // Empty column = end of problem
+                if let Some(op) = current_op {
+                    answers.push(op.apply(&equation));
+                }
+                equation.clear();
+                current_op = None;
+            }
+            Err(_) => {}
+        }
+    }
+
+    if let Some(op) = current_op {
+        answers.push(op.apply(&equation));
+    }
+
+    answers.iter().sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let Some((operator_line, number_lines)) = lines.split_last() else {
+            return Err(ParseError::at_end(input, "expected a worksheet"));
+        };
+
+        for line in number_lines {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_digit() && c != ' ') {
+                return Err(ParseError::unexpected_char(input, line, i, "a digit"));
+            }
+        }
+
+        if let Some(i) = operator_line.find(|c: char| Operator::from_char(c).is_none() && c != ' ')
+        {
+            return Err(ParseError::unexpected_char(
+                input,
+                operator_line,
+                i,
+                "`+` or `*`",
+            ));
+        }
+
+        // Part 1 reads each row's numbers as one per problem.
+        let problems = operator_line.split_whitespace().count();
+        for line in number_lines {
+            let numbers: Vec<&str> = line.split_whitespace().collect();
+            if numbers.len() != problems {
+                let snippet = numbers
+                    .get(problems)
+                    .copied()
+                    .unwrap_or(&line[line.len()..]);
+                return Err(ParseError::at(
+                    input,
+                    snippet,
+                    format!(
+                        "expected {problems} numbers, one per operator, found {}",
+                        numbers.len()
+                    ),
+                ));
+            }
+        }
+
+        Ok(lines.into_iter().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        solve_part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        solve_part2(lines).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use aoc_core::ParseError;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day06::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(6, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day06::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(6, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day06::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(6, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day06::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(6, "input", Part2));
+    }
+
+    #[test]
+    fn rejects_rows_that_do_not_match_the_operators() {
+        let error = |input| {
+            Day06::parse(input)
+                .map(|_| ())
+                .map_err(|e: ParseError| e.render("test.txt"))
+        };
+
+        assert_eq!(
+            error("1 2 3\n4 5\n+ *"),
+            Err("expected 2 numbers, one per operator, found 3\n --> test.txt:1:5\n  |\n1 | 1 2 3\n  |     ^".to_string())
+        );
+        assert_eq!(
+            error("1 2\n4\n+ *"),
+            Err("expected 2 numbers, one per operator, found 1\n --> test.txt:2:2\n  |\n2 | 4\n  |  ^".to_string())
+        );
+        assert_eq!(
+            error("1 2\n+ -"),
+            Err(
+                "expected `+` or `*`, found `-`\n --> test.txt:2:3\n  |\n2 | + -\n  |   ^"
+                    .to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use aoc_core::{Answer, Grid, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Start,
+    Splitter,
+    Empty,
+    Beam,
+}
+
+impl From<char> for Tile {
+    fn from(c: char) -> Self {
+        match c {
+            'S' => Tile::Start,
+            '^' => Tile::Splitter,
+            '|' => Tile::Beam,
+            _ => Tile::Empty,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TachyonManifold {
+    grid: Grid<Tile>,
+}
+
+impl TachyonManifold {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(input, "a manifold tile", |c| {
+            matches!(c, 'S' | '^' | '|' | '.').then(|| Tile::from(c))
+        })?;
+
+        let manifold = Self { grid };
+        if manifold.find_beam_start().is_none() {
+            return Err(ParseError::at_end(input, "expected a beam start `S`"));
+        }
+
+        Ok(manifold)
+    }
+
+    fn extend_beam(&mut self, y: i32, x: i32) {
+        if let Some(cell) = self.grid.get_mut(x, y)
+            && cell == &Tile::Empty
+        {
+            *cell = Tile::Beam;
+        }
+    }
+
+    fn find_beam_start(&self) -> Option<(i32, i32)> {
+        self.grid
+            .find(|&tile| tile == Tile::Start)
+            .map(|(x, y)| (y as i32, x as i32))
+    }
+
+    fn get_tile(&self, y: i32, x: i32) -> Tile {
+        self.grid.get(x, y).copied().unwrap_or(Tile::Empty)
+    }
+
+    pub fn split_count(&mut self) -> i64 {
+        let (start_y, start_x) = self.find_beam_start().expect("Start of beam not found");
+
+        let mut active_beams: HashSet<i32> = HashSet::new();
+        active_beams.insert(start_x);
+
+        let height = self.grid.height() as i32;
+
+        let mut split_count = 0;
+
+        for y in (start_y + 1)..height {
+            let mut new_beams: HashSet<i32> = HashSet::new();
+
+            for &x in &active_beams {
+                match self.get_tile(y, x) {
+                    Tile::Empty => {
+                        self.extend_beam(y, x);
+                        new_beams.insert(x);
+                    }
+                    Tile::Splitter => {
+                        split_count += 1;
+
+                        if self.get_tile(y, x - 1) == Tile::Empty {
+                            self.extend_beam(y, x - 1);
+                            new_beams.insert(x - 1);
+                        }
+                        if self.get_tile(y, x + 1) == Tile::Empty {
+                            self.extend_beam(y, x + 1);
+                            new_beams.insert(x + 1);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            active_beams = new_beams;
+        }
+
+        split_count
+    }
+
+    pub fn timeline_count(&self) -> i64 {
+        let (start_y, start_x) = self.find_beam_start().expect("Start not found");
+
+        let mut timelines: HashMap<i32, i64> = HashMap::new();
+        timelines.insert(start_x, 1);
+
+        let height = self.grid.height() as i32;
+        let width = self.grid.width() as i32;
+
+        for y in (start_y + 1)..height {
+            let mut new_timelines: HashMap<i32, i64> = HashMap::new();
+
+            for (&x, &count) in &timelines {
+                if x < 0 || x >= width {
+                    continue;
+                }
+
+                match self.get_tile(y, x) {
+                    Tile::Empty => {
+                        *new_timelines.entry(x).or_insert(0) += count;
+                    }
+                    Tile::Splitter => {
+                        if x > 0 && self.get_tile(y, x - 1) == Tile::Empty {
+                            *new_timelines.entry(x - 1).or_insert(0) += count;
+                        }
+                        if x + 1 < width && self.get_tile(y, x + 1) == Tile::Empty {
+                            *new_timelines.entry(x + 1).or_insert(0) += count;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            timelines = new_timelines;
+        }
+
+        timelines.values().sum()
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = TachyonManifold;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        TachyonManifold::new(input)
+    }
+
+    fn part1(tachyon_manifold: &Self::Input) -> Answer {
+        tachyon_manifold.clone().split_count().into()
+    }
+
+    fn part2(tachyon_manifold: &Self::Input) -> Answer {
+        tachyon_manifold.timeline_count().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day07::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(7, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day07::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(7, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day07::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(7, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day07::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(7, "input", Part2));
+    }
+}
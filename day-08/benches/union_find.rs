@@ -0,0 +1,43 @@
+use aoc_core::UnionFind;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+/// Pseudo-random pairs of element indices, stable across runs.
+fn pairs(n: usize, count: usize) -> Vec<(usize, usize)> {
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        (state >> 33) as usize % n
+    };
+    (0..count).map(|_| (next(), next())).collect()
+}
+
+fn union_find(c: &mut Criterion) {
+    let mut group = c.benchmark_group("union_find");
+
+    for n in [1_000, 10_000, 100_000] {
+        let pairs = pairs(n, n * 2);
+        group.throughput(Throughput::Elements(pairs.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("union", n), &pairs, |b, pairs| {
+            b.iter(|| {
+                let mut uf = UnionFind::new(n);
+                pairs.iter().filter(|&&(x, y)| uf.union(x, y)).count()
+            })
+        });
+
+        let mut joined = UnionFind::new(n);
+        for &(x, y) in &pairs {
+            joined.union(x, y);
+        }
+        group.bench_with_input(BenchmarkId::new("find", n), &pairs, |b, pairs| {
+            b.iter(|| pairs.iter().map(|&(x, _)| joined.find(x)).sum::<usize>())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, union_find);
+criterion_main!(benches);
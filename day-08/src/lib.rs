@@ -0,0 +1,154 @@
+use aoc_core::{Answer, ParseError, Solution, UnionFind, parse_at};
+
+#[derive(Debug, Clone, Copy)]
+struct JunctionBox {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl JunctionBox {
+    fn squared_distance(&self, other: &JunctionBox) -> i64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+pub struct Playground {
+    boxes: Vec<JunctionBox>,
+}
+
+impl Playground {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let boxes = input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let coords: Vec<i64> = line
+                    .split(',')
+                    .map(|s| parse_at(input, s, "a coordinate"))
+                    .collect::<Result<_, _>>()?;
+                let [x, y, z] = coords[..] else {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "expected three coordinates `X,Y,Z`",
+                    ));
+                };
+                Ok(JunctionBox { x, y, z })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { boxes })
+    }
+
+    /// The example wires up its 10 closest pairs, the real input its 1000 closest.
+    pub fn connections(&self) -> usize {
+        if self.boxes.len() < 1000 { 10 } else { 1000 }
+    }
+
+    pub fn solve(&self, connections: usize) -> i64 {
+        let n = self.boxes.len();
+
+        let mut pairs: Vec<(i64, usize, usize)> = Vec::with_capacity(n * (n - 1) / 2);
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let dist = self.boxes[i].squared_distance(&self.boxes[j]);
+                pairs.push((dist, i, j));
+            }
+        }
+
+        pairs.sort_unstable_by_key(|&(dist, _, _)| dist);
+
+        let mut union_find = UnionFind::new(n);
+
+        for &(_, i, j) in pairs.iter().take(connections) {
+            union_find.union(i, j);
+        }
+
+        let mut sizes = union_find.component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        sizes.iter().take(3).map(|&s| s as i64).product()
+    }
+
+    pub fn last_connection_x_product(&self) -> i64 {
+        let n = self.boxes.len();
+
+        let mut pairs: Vec<(i64, usize, usize)> = Vec::with_capacity(n * (n - 1) / 2);
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let dist = self.boxes[i].squared_distance(&self.boxes[j]);
+                pairs.push((dist, i, j));
+            }
+        }
+
+        pairs.sort_unstable_by_key(|&(dist, _, _)| dist);
+
+        let mut uf = UnionFind::new(n);
+        let mut last_i = 0;
+        let mut last_j = 0;
+
+        for &(_, i, j) in &pairs {
+            if uf.union(i, j) {
+                last_i = i;
+                last_j = j;
+            }
+        }
+
+        self.boxes[last_i].x * self.boxes[last_j].x
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Playground;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Playground::new(input)
+    }
+
+    fn part1(playground: &Self::Input) -> Answer {
+        playground.solve(playground.connections()).into()
+    }
+
+    fn part2(playground: &Self::Input) -> Answer {
+        playground.last_connection_x_product().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day08::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(8, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day08::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(8, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day08::solve_file("./test.txt", Part2);
+        assert_eq!(value, expected(8, "test", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day08::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(8, "input", Part2));
+    }
+}
@@ -0,0 +1,52 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use day_09::Theatre;
+
+/// A loop of `2 * steps + 2` red tiles: a jagged top edge over a flat bottom.
+fn jagged_loop(steps: usize) -> String {
+    let mut tiles = Vec::new();
+    for i in 0..steps {
+        let height = 10 + (i * 7919 % 13) as i64;
+        tiles.push((i as i64 * 4, height));
+        tiles.push((i as i64 * 4 + 4, height));
+    }
+    tiles.push((steps as i64 * 4, 0));
+    tiles.push((0, 0));
+
+    tiles
+        .iter()
+        .map(|(x, y)| format!("{x},{y}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_rectangle_valid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_rectangle_valid");
+
+    for steps in [25, 50, 100] {
+        let theatre = Theatre::new(&jagged_loop(steps)).unwrap();
+        let (v_segs, h_segs) = theatre.build_segments();
+        let tiles = theatre.red_tiles();
+
+        group.throughput(Throughput::Elements(
+            (tiles.len() * (tiles.len() - 1) / 2) as u64,
+        ));
+        group.bench_function(BenchmarkId::from_parameter(tiles.len()), |b| {
+            b.iter(|| {
+                let mut valid = 0;
+                for (i, p1) in tiles.iter().enumerate() {
+                    for p2 in &tiles[i + 1..] {
+                        if theatre.is_rectangle_valid(p1, p2, &v_segs, &h_segs) {
+                            valid += 1;
+                        }
+                    }
+                }
+                valid
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, is_rectangle_valid);
+criterion_main!(benches);
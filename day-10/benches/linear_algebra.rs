@@ -0,0 +1,108 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use day_10::{free_columns, gf2, ilp};
+
+/// Deterministic pseudo-random numbers so every run benchmarks the same systems.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1);
+        (self.0 >> 33) % bound
+    }
+}
+
+/// Random `rows` x `cols` system over GF(2), with the augmented column last.
+fn gf2_system(rows: usize, cols: usize) -> Vec<gf2::BitRow> {
+    let mut rng = Lcg(rows as u64);
+    (0..rows)
+        .map(|_| {
+            let bits: Vec<bool> = (0..=cols).map(|_| rng.next(2) == 1).collect();
+            gf2::BitRow::from_bools(&bits)
+        })
+        .collect()
+}
+
+/// A solvable joltage system: `counters` equations over `counters + free`
+/// buttons, each button wired to its own counter and a random few others.
+fn joltage_system(counters: usize, free: usize) -> (Vec<Vec<i64>>, Vec<i64>) {
+    let mut rng = Lcg(free as u64);
+    let buttons = counters + free;
+    let presses: Vec<i64> = (0..buttons).map(|_| rng.next(20) as i64).collect();
+
+    let mut wiring = vec![vec![0i64; buttons]; counters];
+    for button in 0..buttons {
+        wiring[button % counters][button] = 1;
+        for row in wiring.iter_mut() {
+            if rng.next(4) == 0 {
+                row[button] = 1;
+            }
+        }
+    }
+
+    let targets = wiring
+        .iter()
+        .map(|row| row.iter().zip(&presses).map(|(a, x)| a * x).sum())
+        .collect();
+    (wiring, targets)
+}
+
+fn gaussian_eliminate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gf2::gaussian_eliminate");
+
+    for size in [16, 32, 64, 128] {
+        let matrix = gf2_system(size, size + size / 4);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &matrix, |b, matrix| {
+            b.iter_batched(
+                || matrix.clone(),
+                |mut matrix| gf2::gaussian_eliminate(&mut matrix, size + size / 4),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn find_min_weight_solution(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gf2::find_min_weight_solution");
+
+    for free in [8, 16, 20] {
+        let buttons = 16 + free;
+        let mut matrix = gf2_system(16, buttons);
+        let pivot_cols = gf2::gaussian_eliminate(&mut matrix, buttons);
+        let free_cols = free_columns(buttons, &pivot_cols);
+
+        group.bench_function(BenchmarkId::new("free_vars", free_cols.len()), |b| {
+            b.iter(|| {
+                gf2::find_min_weight_solution(&matrix, &pivot_cols, &free_cols, buttons).unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn minimise_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ilp::minimise_sum");
+
+    for free in [1, 2, 3] {
+        let (matrix, targets) = joltage_system(8, free);
+
+        group.bench_function(BenchmarkId::new("free_vars", free), |b| {
+            b.iter(|| ilp::minimise_sum::<i64>(&matrix, &targets, usize::MAX).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    gaussian_eliminate,
+    find_min_weight_solution,
+    minimise_sum
+);
+criterion_main!(benches);
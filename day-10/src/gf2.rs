@@ -0,0 +1,389 @@
+//! Linear algebra over GF(2), with each row packed into `u64` words.
+
+use crate::TooManyFreeVariables;
+
+/// The most free variables [`find_min_weight_solution`] will search. Split
+/// in half, each half has at most `2^16` choices to weigh.
+pub const MAX_FREE_VARIABLES: usize = 32;
+
+/// The most pivots the two halves of the free variables are matched on: the
+/// match keeps a table entry for every pattern of the pivot bits.
+const MAX_MATCHED_PIVOTS: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitRow {
+    words: Vec<u64>,
+}
+
+impl BitRow {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    pub fn from_bools(bits: &[bool]) -> Self {
+        let mut row = Self::new(bits.len());
+        for (i, &bit) in bits.iter().enumerate() {
+            row.set(i, bit);
+        }
+        row
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, bit: bool) {
+        let mask = 1 << (i % 64);
+        if bit {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    pub fn xor_assign(&mut self, other: &BitRow) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word ^= other;
+        }
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+}
+
+/// Reduces `matrix` to reduced row echelon form in its first `num_cols`
+/// columns, returning the pivot column of each leading row.
+pub fn gaussian_eliminate(matrix: &mut [BitRow], num_cols: usize) -> Vec<usize> {
+    let num_rows = matrix.len();
+    let mut pivot_cols = Vec::new();
+    let mut pivot_row = 0;
+
+    for col in 0..num_cols {
+        let Some(found) = (pivot_row..num_rows).find(|&r| matrix[r].get(col)) else {
+            continue;
+        };
+
+        matrix.swap(pivot_row, found);
+
+        for row in 0..num_rows {
+            if row != pivot_row && matrix[row].get(col) {
+                xor_row(matrix, row, pivot_row);
+            }
+        }
+
+        pivot_cols.push(col);
+        pivot_row += 1;
+    }
+
+    pivot_cols
+}
+
+fn xor_row(matrix: &mut [BitRow], target: usize, source: usize) {
+    let source = matrix[source].clone();
+    matrix[target].xor_assign(&source);
+}
+
+pub fn is_consistent(matrix: &[BitRow], num_pivots: usize, augment_col: usize) -> bool {
+    matrix
+        .iter()
+        .skip(num_pivots)
+        .all(|row| !row.get(augment_col))
+}
+
+/// The solution with the fewest set bits.
+///
+/// Each choice of free variables sets those bits and fixes the pivot bits,
+/// so its weight is the number chosen plus the pivot bits set. The free
+/// variables are split in half, and the choices of each half are matched on
+/// the pivot bits they set; with too many pivots for that, every choice is
+/// weighed in turn.
+///
+/// Expects `matrix` in the form left by [`gaussian_eliminate`].
+pub fn find_min_weight_solution(
+    matrix: &[BitRow],
+    pivot_cols: &[usize],
+    free_cols: &[usize],
+    num_vars: usize,
+) -> Result<BitRow, TooManyFreeVariables> {
+    // A free variable no equation mentions only ever adds weight, so it is left unset.
+    let kernel: Vec<BitRow> = free_cols
+        .iter()
+        .map(|&free| kernel_vector(matrix, pivot_cols, free, num_vars))
+        .filter(|vector| vector.count_ones() > 1)
+        .collect();
+
+    let num_free = kernel.len();
+    if num_free > MAX_FREE_VARIABLES {
+        return Err(TooManyFreeVariables {
+            free: num_free,
+            max: MAX_FREE_VARIABLES,
+        });
+    }
+
+    let solution = particular_solution(matrix, pivot_cols, num_vars);
+    Ok(if pivot_cols.len() <= MAX_MATCHED_PIVOTS {
+        meet_in_the_middle(solution, &kernel, pivot_cols)
+    } else {
+        walk_gray_code(solution, &kernel)
+    })
+}
+
+/// Weighs every choice of free variables in Gray-code order, so each step
+/// flips a single one.
+fn walk_gray_code(mut solution: BitRow, kernel: &[BitRow]) -> BitRow {
+    let mut best = solution.clone();
+    let mut best_weight = solution.count_ones();
+
+    for step in 1..(1u64 << kernel.len()) {
+        solution.xor_assign(&kernel[step.trailing_zeros() as usize]);
+
+        let weight = solution.count_ones();
+        if weight < best_weight {
+            best_weight = weight;
+            best.clone_from(&solution);
+        }
+    }
+
+    best
+}
+
+/// The free variables chosen from one half, and the solution bits that choice
+/// sets.
+#[derive(Clone, Copy)]
+struct Choice {
+    weight: u32,
+    chosen: u32,
+}
+
+impl Choice {
+    const NONE: Choice = Choice {
+        weight: u32::MAX,
+        chosen: 0,
+    };
+}
+
+/// Finds, for each pattern of pivot bits, the lightest choice from the low
+/// half that sets it and the lightest choice from the high half that,
+/// together with the bits each differs in, completes it. The best pattern
+/// then gives the lightest solution.
+fn meet_in_the_middle(mut solution: BitRow, kernel: &[BitRow], pivot_cols: &[usize]) -> BitRow {
+    let pivot_bits = |row: &BitRow| {
+        (0..pivot_cols.len())
+            .filter(|&i| row.get(pivot_cols[i]))
+            .fold(0, |bits, i| bits | 1 << i)
+    };
+    let (low, high) = kernel.split_at(kernel.len() / 2);
+
+    let low_best = lightest_by_pattern(low, 0, pivot_cols.len(), pivot_bits);
+    let mut high_best =
+        lightest_by_pattern(high, pivot_bits(&solution), pivot_cols.len(), pivot_bits);
+    // Differing from a pattern in one more pivot bit costs one more bit set.
+    for bit in 0..pivot_cols.len() {
+        for pattern in 0..high_best.len() {
+            let neighbour = high_best[pattern ^ 1 << bit];
+            if neighbour.weight.saturating_add(1) < high_best[pattern].weight {
+                high_best[pattern] = Choice {
+                    weight: neighbour.weight + 1,
+                    ..neighbour
+                };
+            }
+        }
+    }
+
+    let (low_choice, high_choice) = low_best
+        .into_iter()
+        .zip(high_best)
+        .filter(|(low, _)| low.weight != u32::MAX)
+        .min_by_key(|(low, high)| low.weight + high.weight)
+        .expect("choosing nothing from the low half sets no pivot bits");
+
+    for (half, choice) in [(low, low_choice), (high, high_choice)] {
+        for (i, vector) in half.iter().enumerate() {
+            if choice.chosen >> i & 1 == 1 {
+                solution.xor_assign(vector);
+            }
+        }
+    }
+    solution
+}
+
+/// The lightest choice from `half` giving each pattern of `num_pivots` pivot
+/// bits, starting from the pattern `start`.
+fn lightest_by_pattern(
+    half: &[BitRow],
+    start: usize,
+    num_pivots: usize,
+    pivot_bits: impl Fn(&BitRow) -> usize,
+) -> Vec<Choice> {
+    let flips: Vec<usize> = half.iter().map(pivot_bits).collect();
+    let mut best = vec![Choice::NONE; 1 << num_pivots];
+    best[start] = Choice {
+        weight: 0,
+        chosen: 0,
+    };
+
+    let (mut pattern, mut chosen) = (start, 0u32);
+    for step in 1..(1u32 << half.len()) {
+        let flipped = step.trailing_zeros() as usize;
+        pattern ^= flips[flipped];
+        chosen ^= 1 << flipped;
+
+        let weight = chosen.count_ones();
+        if weight < best[pattern].weight {
+            best[pattern] = Choice { weight, chosen };
+        }
+    }
+
+    best
+}
+
+/// The solution with every free variable unset.
+fn particular_solution(matrix: &[BitRow], pivot_cols: &[usize], num_vars: usize) -> BitRow {
+    let mut solution = BitRow::new(num_vars);
+    for (row, &pivot_col) in pivot_cols.iter().enumerate() {
+        solution.set(pivot_col, matrix[row].get(num_vars));
+    }
+    solution
+}
+
+/// The change to the solution from setting the free variable `free`.
+fn kernel_vector(matrix: &[BitRow], pivot_cols: &[usize], free: usize, num_vars: usize) -> BitRow {
+    let mut vector = BitRow::new(num_vars);
+    vector.set(free, true);
+    for (row, &pivot_col) in pivot_cols.iter().enumerate() {
+        vector.set(pivot_col, matrix[row].get(free));
+    }
+    vector
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::free_columns;
+
+    fn solve(rows: &[&[bool]], num_vars: usize) -> Result<BitRow, TooManyFreeVariables> {
+        let mut matrix: Vec<BitRow> = rows.iter().map(|r| BitRow::from_bools(r)).collect();
+        let pivot_cols = gaussian_eliminate(&mut matrix, num_vars);
+        assert!(is_consistent(&matrix, pivot_cols.len(), num_vars));
+        let free_cols = free_columns(num_vars, &pivot_cols);
+        find_min_weight_solution(&matrix, &pivot_cols, &free_cols, num_vars)
+    }
+
+    /// The fewest presses of `num_vars` buttons, each toggling the lights in
+    /// its mask, that light exactly `target`.
+    fn brute_force(buttons: &[u32], target: u32) -> u32 {
+        (0u32..1 << buttons.len())
+            .filter(|pressed| {
+                let lit = (0..buttons.len())
+                    .filter(|&i| pressed >> i & 1 == 1)
+                    .fold(0, |lit, i| lit ^ buttons[i]);
+                lit == target
+            })
+            .map(u32::count_ones)
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn packs_bits_across_words() {
+        let mut row = BitRow::new(130);
+        row.set(0, true);
+        row.set(64, true);
+        row.set(129, true);
+        row.set(64, false);
+
+        assert!(row.get(0) && !row.get(64) && row.get(129));
+        assert_eq!(row.count_ones(), 2);
+    }
+
+    #[test]
+    fn finds_the_lightest_solution() {
+        // x0 + x1 = 1 and x1 + x2 = 1: both (0,1,0) and (1,0,1) work.
+        let solution = solve(&[&[true, true, false, true], &[false, true, true, true]], 3).unwrap();
+        assert_eq!(solution, BitRow::from_bools(&[false, true, false]));
+    }
+
+    #[test]
+    fn handles_systems_wider_than_a_word() {
+        // Every one of 100 buttons toggles the single light, leaving 99 free.
+        let mut matrix = vec![BitRow::from_bools(&[true; 101])];
+        let pivot_cols = gaussian_eliminate(&mut matrix, 100);
+        let free_cols = free_columns(100, &pivot_cols);
+
+        assert_eq!(
+            find_min_weight_solution(&matrix, &pivot_cols, &free_cols, 100),
+            Err(TooManyFreeVariables { free: 99, max: 32 })
+        );
+
+        // Buttons wired to nothing add no free variables worth searching.
+        let narrow: Vec<bool> = (0..=100).map(|i| i < 20 || i == 100).collect();
+        let solution = solve(&[&narrow], 100).unwrap();
+        assert_eq!(solution.count_ones(), 1);
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_systems() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as u32
+        };
+
+        for _ in 0..20 {
+            let (num_lights, num_buttons) = (6, 14);
+            let buttons: Vec<u32> = (0..num_buttons)
+                .map(|_| next() & ((1 << num_lights) - 1))
+                .collect();
+            // Pressing a random set of buttons guarantees a solution exists.
+            let pressed = next();
+            let target = (0..num_buttons)
+                .filter(|&i| pressed >> i & 1 == 1)
+                .fold(0, |lit, i| lit ^ buttons[i]);
+
+            let rows: Vec<Vec<bool>> = (0..num_lights)
+                .map(|light| {
+                    (0..=num_buttons)
+                        .map(|i| match i {
+                            i if i == num_buttons => target >> light & 1 == 1,
+                            i => buttons[i] >> light & 1 == 1,
+                        })
+                        .collect()
+                })
+                .collect();
+            let mut matrix: Vec<BitRow> = rows.iter().map(|r| BitRow::from_bools(r)).collect();
+            let pivot_cols = gaussian_eliminate(&mut matrix, num_buttons);
+            let free_cols = free_columns(num_buttons, &pivot_cols);
+            let kernel: Vec<BitRow> = free_cols
+                .iter()
+                .map(|&free| kernel_vector(&matrix, &pivot_cols, free, num_buttons))
+                .collect();
+            let particular = particular_solution(&matrix, &pivot_cols, num_buttons);
+
+            let expected = brute_force(&buttons, target);
+            let matched = meet_in_the_middle(particular.clone(), &kernel, &pivot_cols);
+            let walked = walk_gray_code(particular, &kernel);
+            assert_eq!(matched.count_ones(), expected, "{buttons:?} -> {target}");
+            assert_eq!(walked.count_ones(), expected, "{buttons:?} -> {target}");
+            assert_eq!(
+                find_min_weight_solution(&matrix, &pivot_cols, &free_cols, num_buttons)
+                    .unwrap()
+                    .count_ones(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn searches_the_most_free_variables() {
+        // Each of 33 buttons toggles the light, leaving 32 free: any one
+        // button alone turns it on, as brute force over fewer buttons shows.
+        let solution = solve(&[&[true; 34]], 33).unwrap();
+
+        assert_eq!(solution.count_ones(), brute_force(&[1; 8], 1));
+    }
+}
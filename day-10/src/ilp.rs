@@ -0,0 +1,478 @@
+//! Minimises `x_1 + ... + x_n` subject to `A x = b` over the non-negative
+//! integers, by branch-and-bound over an exact simplex relaxation.
+//!
+//! Every node solves the LP relaxation with the current variable bounds from
+//! scratch. A node is pruned when its relaxation is infeasible or cannot beat
+//! the best integer solution so far; otherwise it branches on its first
+//! fractional variable. Exploring every node that is not pruned proves the
+//! result optimal.
+
+use aoc_core::rational::RationalInt;
+use aoc_core::{Deadline, Rational, RationalError};
+
+/// Integers the relaxation can be solved over: `i64` for speed, with `BigInt`
+/// as the fallback when a pivot overflows.
+pub trait Scalar: RationalInt + From<i64> {}
+
+impl<T: RationalInt + From<i64>> Scalar for T {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optimum {
+    pub values: Vec<i64>,
+    pub total: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The best solution, proven optimal.
+    Optimal(Optimum),
+    /// No non-negative integer solution exists.
+    Infeasible,
+    /// The search stopped early, with the best solution found so far.
+    NodeLimit(Option<Optimum>),
+    /// The deadline passed, with the best solution found so far.
+    TimedOut(Option<Optimum>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub outcome: Outcome,
+    pub nodes: usize,
+}
+
+/// Solves the system `matrix * x = rhs`, exploring at most `node_limit` nodes.
+///
+/// Fails only if `T` is too narrow for the intermediate values.
+pub fn minimise_sum<T: Scalar>(
+    matrix: &[Vec<i64>],
+    rhs: &[i64],
+    node_limit: usize,
+) -> Result<Report, RationalError> {
+    minimise_sum_until::<T>(matrix, rhs, node_limit, Deadline::NEVER)
+}
+
+/// Like [`minimise_sum`], but also stops once `deadline` passes.
+pub fn minimise_sum_until<T: Scalar>(
+    matrix: &[Vec<i64>],
+    rhs: &[i64],
+    node_limit: usize,
+    deadline: Deadline,
+) -> Result<Report, RationalError> {
+    let num_vars = matrix.first().map_or(0, Vec::len);
+    let mut stack = vec![Bounds {
+        lower: vec![0; num_vars],
+        upper: vec![None; num_vars],
+    }];
+    let mut best: Option<Optimum> = None;
+    let mut nodes = 0;
+
+    while let Some(bounds) = stack.pop() {
+        if nodes == node_limit {
+            return Ok(Report {
+                outcome: Outcome::NodeLimit(best),
+                nodes,
+            });
+        }
+        if deadline.has_passed() {
+            return Ok(Report {
+                outcome: Outcome::TimedOut(best),
+                nodes,
+            });
+        }
+        nodes += 1;
+
+        let Some(relaxed) = relaxation::<T>(matrix, rhs, &bounds)? else {
+            continue;
+        };
+
+        if best
+            .as_ref()
+            .is_some_and(|b| relaxed.total.ceil() >= T::from(b.total))
+        {
+            continue;
+        }
+
+        match relaxed.values.iter().position(|v| !v.is_integer()) {
+            None => {
+                let values = relaxed
+                    .values
+                    .iter()
+                    .map(|v| v.floor().to_i64())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(RationalError::Overflow)?;
+                let total = values.iter().sum();
+                best = Some(Optimum { values, total });
+            }
+            Some(var) => {
+                let value = &relaxed.values[var];
+                let (floor, ceil) = (to_i64(value.floor())?, to_i64(value.ceil())?);
+
+                let mut up = bounds.clone();
+                up.lower[var] = ceil;
+                let mut down = bounds;
+                down.upper[var] = Some(floor);
+
+                // Popped first, so rounding down is explored before rounding up.
+                stack.push(up);
+                stack.push(down);
+            }
+        }
+    }
+
+    let outcome = match best {
+        Some(optimum) => Outcome::Optimal(optimum),
+        None => Outcome::Infeasible,
+    };
+    Ok(Report { outcome, nodes })
+}
+
+fn to_i64<T: Scalar>(n: T) -> Result<i64, RationalError> {
+    n.to_i64().ok_or(RationalError::Overflow)
+}
+
+#[derive(Debug, Clone)]
+struct Bounds {
+    lower: Vec<i64>,
+    upper: Vec<Option<i64>>,
+}
+
+struct Relaxation<T> {
+    values: Vec<Rational<T>>,
+    total: Rational<T>,
+}
+
+/// Solves the LP relaxation within `bounds`, or `None` if it is infeasible.
+///
+/// Each variable is shifted to `y = x - lower`, and each upper bound becomes
+/// an extra row `y + s = upper - lower` with its own slack `s`.
+fn relaxation<T: Scalar>(
+    matrix: &[Vec<i64>],
+    rhs: &[i64],
+    bounds: &Bounds,
+) -> Result<Option<Relaxation<T>>, RationalError> {
+    let num_vars = bounds.lower.len();
+    let capped: Vec<(usize, i64)> = bounds
+        .upper
+        .iter()
+        .enumerate()
+        .filter_map(|(var, upper)| upper.map(|u| (var, u - bounds.lower[var])))
+        .collect();
+    if capped.iter().any(|&(_, room)| room < 0) {
+        return Ok(None);
+    }
+
+    let num_slacks = capped.len();
+    let first_artificial = num_vars + num_slacks;
+    let width = first_artificial + matrix.len() + 1;
+    let int = |n: i64| Rational::from(T::from(n));
+
+    let mut tableau = Tableau {
+        rows: Vec::new(),
+        basis: Vec::new(),
+        objective: vec![Rational::zero(); width],
+    };
+
+    for (i, (row, &target)) in matrix.iter().zip(rhs).enumerate() {
+        let shifted = row
+            .iter()
+            .zip(&bounds.lower)
+            .try_fold(target, |acc, (&a, &l)| acc.checked_sub(a.checked_mul(l)?))
+            .ok_or(RationalError::Overflow)?;
+        let sign = if shifted < 0 { -1 } else { 1 };
+
+        let mut cells = vec![Rational::zero(); width];
+        for (var, &a) in row.iter().enumerate() {
+            cells[var] = int(sign * a);
+        }
+        cells[first_artificial + i] = Rational::one();
+        cells[width - 1] = int(sign * shifted);
+
+        tableau.rows.push(cells);
+        tableau.basis.push(first_artificial + i);
+    }
+
+    for (k, &(var, room)) in capped.iter().enumerate() {
+        let mut cells = vec![Rational::zero(); width];
+        cells[var] = Rational::one();
+        cells[num_vars + k] = Rational::one();
+        cells[width - 1] = int(room);
+
+        tableau.rows.push(cells);
+        tableau.basis.push(num_vars + k);
+    }
+
+    // Phase one drives the artificial variables to zero, if it can.
+    let artificial_cost = |col: usize| i64::from(col >= first_artificial);
+    tableau.set_objective(|col| int(artificial_cost(col)))?;
+    tableau.optimise(width - 1)?;
+    if !tableau.objective[width - 1].is_zero() {
+        return Ok(None);
+    }
+    tableau.drive_out_artificials(first_artificial)?;
+
+    // Phase two minimises the sum of the original variables.
+    tableau.set_objective(|col| int(i64::from(col < num_vars)))?;
+    tableau.optimise(first_artificial)?;
+
+    let mut values: Vec<Rational<T>> = bounds.lower.iter().map(|&l| int(l)).collect();
+    for (row, &col) in tableau.rows.iter().zip(&tableau.basis) {
+        if col < num_vars {
+            values[col] = checked(values[col].checked_add(&row[width - 1]))?;
+        }
+    }
+    let total = values
+        .iter()
+        .try_fold(Rational::zero(), |acc, v| acc.checked_add(v))
+        .ok_or(RationalError::Overflow)?;
+
+    Ok(Some(Relaxation { values, total }))
+}
+
+fn checked<T>(value: Option<T>) -> Result<T, RationalError> {
+    value.ok_or(RationalError::Overflow)
+}
+
+/// A dense simplex tableau with the right-hand side in the last column.
+///
+/// `objective` holds the reduced costs, and the negated objective value in
+/// its last column.
+struct Tableau<T> {
+    rows: Vec<Vec<Rational<T>>>,
+    basis: Vec<usize>,
+    objective: Vec<Rational<T>>,
+}
+
+impl<T: Scalar> Tableau<T> {
+    fn rhs(&self) -> usize {
+        self.objective.len() - 1
+    }
+
+    /// Prices out the basic columns of the objective with costs `cost(col)`.
+    fn set_objective(&mut self, cost: impl Fn(usize) -> Rational<T>) -> Result<(), RationalError> {
+        self.objective = (0..self.objective.len())
+            .map(|col| {
+                if col == self.rhs() {
+                    Rational::zero()
+                } else {
+                    cost(col)
+                }
+            })
+            .collect();
+
+        for (row, &basic) in self.rows.iter().zip(&self.basis) {
+            let weight = cost(basic);
+            if weight.is_zero() {
+                continue;
+            }
+            for (cell, a) in self.objective.iter_mut().zip(row) {
+                *cell = checked(weight.checked_mul(a).and_then(|wa| cell.checked_sub(&wa)))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pivots until no column before `columns` has a negative reduced cost.
+    /// Bland's rule picks the lowest-indexed candidates, so it never cycles.
+    fn optimise(&mut self, columns: usize) -> Result<(), RationalError> {
+        while let Some(col) = (0..columns).find(|&c| self.objective[c].is_negative()) {
+            let rhs = self.rhs();
+            let mut leaving: Option<(usize, Rational<T>)> = None;
+
+            for (i, row) in self.rows.iter().enumerate() {
+                if !row[col].is_negative() && !row[col].is_zero() {
+                    let ratio = checked(row[rhs].checked_div(&row[col]))?;
+                    let better = leaving.as_ref().is_none_or(|(r, best)| {
+                        ratio < *best || (ratio == *best && self.basis[i] < self.basis[*r])
+                    });
+                    if better {
+                        leaving = Some((i, ratio));
+                    }
+                }
+            }
+
+            // Both objectives are bounded below by zero, so some row always leaves.
+            let (row, _) = leaving.expect("objective is bounded below");
+            self.pivot(row, col)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces any artificial variables left in the basis (at zero), and
+    /// drops rows that turn out to be redundant.
+    fn drive_out_artificials(&mut self, first_artificial: usize) -> Result<(), RationalError> {
+        let mut row = 0;
+        while row < self.rows.len() {
+            if self.basis[row] < first_artificial {
+                row += 1;
+                continue;
+            }
+
+            match (0..first_artificial).find(|&c| !self.rows[row][c].is_zero()) {
+                Some(col) => {
+                    self.pivot(row, col)?;
+                    row += 1;
+                }
+                None => {
+                    self.rows.remove(row);
+                    self.basis.remove(row);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pivot(&mut self, row: usize, col: usize) -> Result<(), RationalError> {
+        let pivot = self.rows[row][col].clone();
+        for cell in self.rows[row].iter_mut() {
+            *cell = checked(cell.checked_div(&pivot))?;
+        }
+
+        let pivot_row = self.rows[row].clone();
+        let others = self
+            .rows
+            .iter_mut()
+            .enumerate()
+            .filter(|&(i, _)| i != row)
+            .map(|(_, r)| r)
+            .chain([&mut self.objective]);
+
+        for other in others {
+            let factor = other[col].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for (cell, a) in other.iter_mut().zip(&pivot_row) {
+                *cell = checked(factor.checked_mul(a).and_then(|fa| cell.checked_sub(&fa)))?;
+            }
+        }
+
+        self.basis[row] = col;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use proptest::prelude::*;
+
+    /// The first example machine: `(3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}`.
+    fn example() -> (Vec<Vec<i64>>, Vec<i64>) {
+        let matrix = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        (matrix, vec![3, 5, 4, 7])
+    }
+
+    fn optimal_total(report: &Report) -> Option<i64> {
+        match &report.outcome {
+            Outcome::Optimal(optimum) => Some(optimum.total),
+            _ => None,
+        }
+    }
+
+    /// Smallest sum over every assignment with each variable at most `max`.
+    fn brute_force(matrix: &[Vec<i64>], rhs: &[i64], max: i64) -> Option<i64> {
+        let num_vars = matrix[0].len();
+        let mut values = vec![0; num_vars];
+        let mut best = None;
+
+        loop {
+            let solves = matrix
+                .iter()
+                .zip(rhs)
+                .all(|(row, &b)| row.iter().zip(&values).map(|(a, x)| a * x).sum::<i64>() == b);
+            if solves {
+                let total: i64 = values.iter().sum();
+                best = Some(best.map_or(total, |b: i64| b.min(total)));
+            }
+
+            let Some(var) = values.iter().position(|&v| v < max) else {
+                return best;
+            };
+            values[var] += 1;
+            values[..var].fill(0);
+        }
+    }
+
+    #[test]
+    fn finds_the_example_optimum() {
+        let (matrix, rhs) = example();
+        let report = minimise_sum::<i64>(&matrix, &rhs, usize::MAX).unwrap();
+
+        let Outcome::Optimal(optimum) = report.outcome else {
+            panic!("expected an optimum, got {:?}", report.outcome);
+        };
+        assert_eq!(optimum.total, 10);
+        for (row, &b) in matrix.iter().zip(&rhs) {
+            let sum: i64 = row.iter().zip(&optimum.values).map(|(a, x)| a * x).sum();
+            assert_eq!(sum, b);
+        }
+    }
+
+    #[test]
+    fn reports_infeasible_systems() {
+        let report = minimise_sum::<i64>(&[vec![2]], &[3], usize::MAX).unwrap();
+        assert_eq!(report.outcome, Outcome::Infeasible);
+
+        let report = minimise_sum::<i64>(&[vec![1, 1], vec![1, 1]], &[1, 2], usize::MAX).unwrap();
+        assert_eq!(report.outcome, Outcome::Infeasible);
+    }
+
+    #[test]
+    fn stops_at_the_node_limit() {
+        // The relaxation is x = 3/2, so proving there is no integer solution
+        // takes more than one node.
+        let report = minimise_sum::<i64>(&[vec![2]], &[3], 1).unwrap();
+        assert_eq!(report.outcome, Outcome::NodeLimit(None));
+        assert_eq!(report.nodes, 1);
+    }
+
+    #[test]
+    fn stops_at_the_deadline() {
+        let (matrix, rhs) = example();
+        let deadline = Deadline::after(std::time::Duration::ZERO);
+        let report = minimise_sum_until::<i64>(&matrix, &rhs, usize::MAX, deadline).unwrap();
+        assert_eq!(report.outcome, Outcome::TimedOut(None));
+        assert_eq!(report.nodes, 0);
+    }
+
+    #[test]
+    fn overflowing_pivots_succeed_over_bigints() {
+        let (a, b) = (10_000_000_000, 10_000_000_001);
+        let matrix = [vec![a, b], vec![b, a]];
+
+        assert_eq!(
+            minimise_sum::<i64>(&matrix, &[a + b, a + b], usize::MAX),
+            Err(RationalError::Overflow)
+        );
+        let report = minimise_sum::<BigInt>(&matrix, &[a + b, a + b], usize::MAX).unwrap();
+        assert_eq!(optimal_total(&report), Some(2));
+    }
+
+    fn wiring() -> impl Strategy<Value = (Vec<Vec<i64>>, Vec<i64>)> {
+        (1..=3usize, 1..=4usize).prop_flat_map(|(rows, cols)| {
+            (
+                prop::collection::vec(prop::collection::vec(0..=1i64, cols), rows),
+                prop::collection::vec(0..=4i64, rows),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force((matrix, rhs) in wiring()) {
+            let report = minimise_sum::<i64>(&matrix, &rhs, usize::MAX).unwrap();
+            // With 0/1 coefficients no useful press count exceeds the largest target.
+            let max = rhs.iter().copied().max().unwrap_or(0);
+            prop_assert_eq!(optimal_total(&report), brute_force(&matrix, &rhs, max));
+        }
+    }
+}
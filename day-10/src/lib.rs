@@ -0,0 +1,836 @@
+use aoc_core::{
+    Answer, Batch, BatchOptions, Deadline, ParseError, Part, RationalError, Solution, TimedOut,
+    parse_at,
+};
+use num_bigint::BigInt;
+use std::fmt;
+
+pub mod gf2;
+pub mod ilp;
+pub mod modular;
+
+/// Branch-and-bound nodes explored per machine before giving up on it.
+pub const NODE_LIMIT: usize = 100_000;
+
+/// The most states a light can cycle through, so each fits in one digit.
+pub const MAX_LIGHT_STATES: u32 = 10;
+
+#[derive(Debug, Clone)]
+struct Indicator {
+    desired_state: u32,
+    current_state: u32,
+}
+
+/// A machine, written as its lights, buttons and joltages:
+///
+/// ```text
+/// [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+/// ```
+///
+/// The lights may also give the pattern they start in, before a `>`, and a
+/// number of states `%k` for lights that cycle through `0..k` with each press
+/// rather than toggling. `.` and `#` stand for states 0 and 1:
+///
+/// ```text
+/// [#..#>.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+/// [0120>2001]%3 (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+/// ```
+#[derive(Debug, Clone)]
+pub struct Machine {
+    indicators: Vec<Indicator>,
+    states: u32,
+    buttons: Vec<Vec<i32>>,
+    joltages: Vec<i32>,
+}
+
+/// How many times to press each button, indexed like [`Machine::buttons`].
+pub type Plan = Vec<i64>;
+
+#[derive(Debug, Clone)]
+pub struct Factory {
+    machines: Vec<Machine>,
+}
+
+/// The light search has more free buttons than it can enumerate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyFreeVariables {
+    pub free: usize,
+    pub max: usize,
+}
+
+impl fmt::Display for TooManyFreeVariables {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} free buttons is too many to search; at most {} are supported",
+            self.free, self.max
+        )
+    }
+}
+
+impl std::error::Error for TooManyFreeVariables {}
+
+fn parse_light_states(input: &str, lights: &str, states: u32) -> Result<Vec<u32>, ParseError> {
+    lights
+        .char_indices()
+        .map(|(i, c)| match c {
+            '.' => Ok(0),
+            '#' => Ok(1),
+            _ => c
+                .to_digit(10)
+                .filter(|&d| states > 2 && d < states)
+                .ok_or_else(|| {
+                    let expected = if states == 2 {
+                        "`#` or `.`".to_string()
+                    } else {
+                        format!("a light state below {states}")
+                    };
+                    ParseError::unexpected_char(input, lights, i, &expected)
+                }),
+        })
+        .collect()
+}
+
+impl Machine {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let line = line.trim();
+
+        let (indicators, states, rest) = Self::parse_indicators(input, line)?;
+
+        let joltage_start = rest
+            .find('{')
+            .ok_or_else(|| ParseError::at(input, rest, "expected joltages `{...}`"))?;
+        let joltages = Self::parse_joltages(input, &rest[joltage_start..])?;
+        let buttons = Self::parse_buttons(input, &rest[..joltage_start])?;
+
+        Ok(Machine {
+            indicators,
+            states,
+            buttons,
+            joltages,
+        })
+    }
+
+    /// Parses the `[...]` indicator lights and any `%k` after them, returning
+    /// them with the rest of the line.
+    fn parse_indicators<'a>(
+        input: &str,
+        line: &'a str,
+    ) -> Result<(Vec<Indicator>, u32, &'a str), ParseError> {
+        let start = line
+            .find('[')
+            .ok_or_else(|| ParseError::at(input, line, "expected indicator lights `[...]`"))?;
+        let end = start
+            + line[start..]
+                .find(']')
+                .ok_or_else(|| ParseError::at(input, &line[start..], "expected `]`"))?;
+
+        let mut rest = &line[end + 1..];
+        let mut states = 2;
+        if let Some(after) = rest.strip_prefix('%') {
+            let digits = &after[..after.find(char::is_whitespace).unwrap_or(after.len())];
+            states = parse_at(input, digits, "a number of light states")?;
+            if !(2..=MAX_LIGHT_STATES).contains(&states) {
+                return Err(ParseError::at(
+                    input,
+                    digits,
+                    format!("expected between 2 and {MAX_LIGHT_STATES} light states"),
+                ));
+            }
+            rest = &after[digits.len()..];
+        }
+
+        let lights = &line[start + 1..end];
+        let (initial, desired) = match lights.split_once('>') {
+            Some((initial, desired)) => (Some(initial), desired),
+            None => (None, lights),
+        };
+
+        let desired = parse_light_states(input, desired, states)?;
+        let current = match initial {
+            Some(initial) => {
+                let current = parse_light_states(input, initial, states)?;
+                if current.len() != desired.len() {
+                    return Err(ParseError::at(
+                        input,
+                        initial,
+                        format!(
+                            "expected a starting pattern of {} lights, found {}",
+                            desired.len(),
+                            current.len()
+                        ),
+                    ));
+                }
+                current
+            }
+            None => vec![0; desired.len()],
+        };
+
+        let indicators = desired
+            .into_iter()
+            .zip(current)
+            .map(|(desired_state, current_state)| Indicator {
+                desired_state,
+                current_state,
+            })
+            .collect();
+
+        Ok((indicators, states, rest))
+    }
+
+    fn parse_joltages(input: &str, s: &str) -> Result<Vec<i32>, ParseError> {
+        let start = s
+            .find('{')
+            .ok_or_else(|| ParseError::at(input, s, "expected `{`"))?;
+        let end = start
+            + s[start..]
+                .find('}')
+                .ok_or_else(|| ParseError::at(input, &s[start..], "expected `}`"))?;
+
+        s[start + 1..end]
+            .split(',')
+            .map(|n| parse_at(input, n.trim(), "a joltage"))
+            .collect()
+    }
+
+    fn parse_buttons(input: &str, s: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        s.split(')')
+            .filter_map(|part| part.find('(').map(|i| &part[i + 1..]))
+            .map(|part| {
+                part.split(',')
+                    .map(|n| parse_at(input, n.trim(), "a counter index"))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Factory {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let machines = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Machine::parse(input, line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Factory { machines })
+    }
+
+    pub fn machines(&self) -> &[Machine] {
+        &self.machines
+    }
+
+    /// `Ok(None)` if any machine's lights cannot be set.
+    pub fn total_min_presses(&self) -> Result<Option<i64>, TooManyFreeVariables> {
+        let mut total = Some(0);
+        for machine in &self.machines {
+            total = total.zip(machine.min_presses()?).map(|(t, p)| t + p as i64);
+        }
+        Ok(total)
+    }
+
+    /// `None` if any machine has no solution or none proven optimal within
+    /// [`NODE_LIMIT`] nodes.
+    pub fn total_min_joltage_presses(&self) -> Option<i64> {
+        self.joltage_reports_with(&Batch::new("machines"))
+            .into_iter()
+            .map(|report| match report.outcome {
+                ilp::Outcome::Optimal(optimum) => Some(optimum.total),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// The branch-and-bound result for each machine within [`NODE_LIMIT`]
+    /// nodes, in input order, solved as `batch` says. A machine that runs
+    /// out of time reports [`ilp::Outcome::TimedOut`].
+    pub fn joltage_reports_with(&self, batch: &Batch) -> Vec<ilp::Report> {
+        batch
+            .run(&self.machines, |machine, deadline| {
+                Ok(machine.joltage_report_until(NODE_LIMIT, deadline))
+            })
+            .into_iter()
+            .map(|report| report.expect("timeouts are reported in the outcome"))
+            .collect()
+    }
+
+    /// The branch-and-bound result for each machine, in input order.
+    pub fn joltage_reports(&self, node_limit: usize) -> Vec<ilp::Report> {
+        self.machines
+            .iter()
+            .map(|m| m.joltage_report(node_limit))
+            .collect()
+    }
+}
+
+impl Machine {
+    pub fn buttons(&self) -> &[Vec<i32>] {
+        &self.buttons
+    }
+
+    pub fn min_presses(&self) -> Result<Option<usize>, TooManyFreeVariables> {
+        Ok(self
+            .light_plan()?
+            .map(|plan| plan.iter().sum::<i64>() as usize))
+    }
+
+    /// The number of states each light cycles through.
+    pub fn light_states(&self) -> u32 {
+        self.states
+    }
+
+    /// A fewest-presses plan that sets every light as desired. Pressing a
+    /// button `k` times brings its lights back round, so each is pressed
+    /// fewer than `k` times.
+    pub fn light_plan(&self) -> Result<Option<Plan>, TooManyFreeVariables> {
+        let num_buttons = self.buttons.len();
+
+        if num_buttons == 0 {
+            return Ok(self
+                .indicators
+                .iter()
+                .all(|i| i.current_state == i.desired_state)
+                .then(Vec::new));
+        }
+
+        if self.states != 2 {
+            return self.modular_light_plan();
+        }
+
+        let mut matrix = self.build_gf2_matrix();
+        let pivot_cols = gf2::gaussian_eliminate(&mut matrix, num_buttons);
+
+        if !gf2::is_consistent(&matrix, pivot_cols.len(), num_buttons) {
+            return Ok(None);
+        }
+
+        let free_cols = free_columns(num_buttons, &pivot_cols);
+        let solution =
+            gf2::find_min_weight_solution(&matrix, &pivot_cols, &free_cols, num_buttons)?;
+        Ok(Some(
+            (0..num_buttons)
+                .map(|btn| i64::from(solution.get(btn)))
+                .collect(),
+        ))
+    }
+
+    /// Lights with more than two states are solved over Z/kZ instead of GF(2).
+    fn modular_light_plan(&self) -> Result<Option<Plan>, TooManyFreeVariables> {
+        let num_buttons = self.buttons.len();
+        let mut matrix = self.build_modular_matrix();
+        let pivot_cols = modular::eliminate(&mut matrix, num_buttons, self.states);
+
+        if !modular::is_consistent(&matrix, pivot_cols.len(), num_buttons) {
+            return Ok(None);
+        }
+
+        let solution = modular::find_min_solution(&matrix, &pivot_cols, num_buttons, self.states)?;
+        Ok(solution.map(|presses| presses.into_iter().map(i64::from).collect()))
+    }
+
+    /// The lights after pressing each button `plan[i]` times, starting from
+    /// their current state.
+    pub fn simulate_lights(&self, plan: &[i64]) -> Vec<u32> {
+        let states = i64::from(self.states);
+        let mut lights: Vec<i64> = self
+            .indicators
+            .iter()
+            .map(|i| i64::from(i.current_state))
+            .collect();
+
+        for (button, &presses) in self.buttons.iter().zip(plan) {
+            for &idx in button {
+                if let Some(light) = lights.get_mut(idx as usize) {
+                    *light = (*light + presses).rem_euclid(states);
+                }
+            }
+        }
+
+        lights.into_iter().map(|light| light as u32).collect()
+    }
+
+    pub fn lights_reached(&self, plan: &[i64]) -> bool {
+        plan.len() == self.buttons.len()
+            && plan.iter().all(|&p| p >= 0)
+            && self
+                .simulate_lights(plan)
+                .into_iter()
+                .eq(self.indicators.iter().map(|i| i.desired_state))
+    }
+
+    fn build_gf2_matrix(&self) -> Vec<gf2::BitRow> {
+        let num_indicators = self.indicators.len();
+        let num_buttons = self.buttons.len();
+
+        let mut matrix = vec![gf2::BitRow::new(num_buttons + 1); num_indicators];
+
+        for (btn, button) in self.buttons.iter().enumerate() {
+            for &idx in button {
+                if (idx as usize) < num_indicators {
+                    matrix[idx as usize].set(btn, true);
+                }
+            }
+        }
+
+        for (i, ind) in self.indicators.iter().enumerate() {
+            matrix[i].set(num_buttons, ind.desired_state != ind.current_state);
+        }
+
+        matrix
+    }
+
+    /// Each light must advance by `desired - current` mod `k`.
+    fn build_modular_matrix(&self) -> Vec<Vec<u32>> {
+        let num_indicators = self.indicators.len();
+        let num_buttons = self.buttons.len();
+
+        let mut matrix = vec![vec![0; num_buttons + 1]; num_indicators];
+
+        for (btn, button) in self.buttons.iter().enumerate() {
+            for &idx in button {
+                if (idx as usize) < num_indicators {
+                    let coefficient = &mut matrix[idx as usize][btn];
+                    *coefficient = (*coefficient + 1) % self.states;
+                }
+            }
+        }
+
+        for (i, ind) in self.indicators.iter().enumerate() {
+            matrix[i][num_buttons] =
+                (ind.desired_state + self.states - ind.current_state) % self.states;
+        }
+
+        matrix
+    }
+}
+
+impl Machine {
+    fn joltage_report(&self, node_limit: usize) -> ilp::Report {
+        self.joltage_report_until(node_limit, Deadline::NEVER)
+    }
+
+    /// Solved over `i64` first, retrying with `BigInt` if any pivot overflows.
+    fn joltage_report_until(&self, node_limit: usize, deadline: Deadline) -> ilp::Report {
+        let (matrix, rhs) = self.joltage_system();
+
+        match ilp::minimise_sum_until::<i64>(&matrix, &rhs, node_limit, deadline) {
+            Err(RationalError::Overflow) => {
+                ilp::minimise_sum_until::<BigInt>(&matrix, &rhs, node_limit, deadline)
+                    .expect("BigInt arithmetic cannot overflow")
+            }
+            report => report.expect("the simplex never divides by zero"),
+        }
+    }
+
+    pub fn min_joltage_presses(&self) -> Option<i64> {
+        self.joltage_plan().map(|plan| plan.iter().sum())
+    }
+
+    /// A fewest-presses plan that brings every counter to its joltage,
+    /// proven optimal within [`NODE_LIMIT`] nodes.
+    pub fn joltage_plan(&self) -> Option<Plan> {
+        self.joltage_plan_until(Deadline::NEVER)
+            .expect("a search without a deadline cannot time out")
+    }
+
+    /// Like [`joltage_plan`](Self::joltage_plan), but gives up once
+    /// `deadline` passes.
+    pub fn joltage_plan_until(&self, deadline: Deadline) -> Result<Option<Plan>, TimedOut> {
+        match self.joltage_report_until(NODE_LIMIT, deadline).outcome {
+            ilp::Outcome::Optimal(optimum) => Ok(Some(optimum.values)),
+            ilp::Outcome::Infeasible | ilp::Outcome::NodeLimit(_) => Ok(None),
+            ilp::Outcome::TimedOut(_) => Err(TimedOut),
+        }
+    }
+
+    /// The counters after pressing each button `plan[i]` times, starting from zero.
+    pub fn simulate_joltages(&self, plan: &[i64]) -> Vec<i64> {
+        let mut counters = vec![0; self.joltages.len()];
+
+        for (button, &presses) in self.buttons.iter().zip(plan) {
+            for &idx in button {
+                if let Some(counter) = counters.get_mut(idx as usize) {
+                    *counter += presses;
+                }
+            }
+        }
+
+        counters
+    }
+
+    pub fn joltages_reached(&self, plan: &[i64]) -> bool {
+        plan.len() == self.buttons.len()
+            && plan.iter().all(|&p| p >= 0)
+            && self
+                .simulate_joltages(plan)
+                .into_iter()
+                .eq(self.joltages.iter().map(|&j| j as i64))
+    }
+
+    /// `plan` as the buttons to press, e.g. `(0,2) x3 (1)`, skipping unpressed ones.
+    pub fn describe(&self, plan: &[i64]) -> String {
+        self.buttons
+            .iter()
+            .zip(plan)
+            .filter(|&(_, &presses)| presses > 0)
+            .map(|(button, &presses)| {
+                let wires: Vec<String> = button.iter().map(i32::to_string).collect();
+                match presses {
+                    1 => format!("({})", wires.join(",")),
+                    n => format!("({}) x{n}", wires.join(",")),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// One equation per counter, with a 0/1 coefficient for each button wired to it.
+    fn joltage_system(&self) -> (Vec<Vec<i64>>, Vec<i64>) {
+        let num_counters = self.joltages.len();
+        let mut matrix = vec![vec![0; self.buttons.len()]; num_counters];
+
+        for (btn, button) in self.buttons.iter().enumerate() {
+            for &idx in button {
+                if (idx as usize) < num_counters {
+                    matrix[idx as usize][btn] = 1;
+                }
+            }
+        }
+
+        let rhs = self.joltages.iter().map(|&j| j as i64).collect();
+        (matrix, rhs)
+    }
+}
+
+pub fn free_columns(num_vars: usize, pivot_cols: &[usize]) -> Vec<usize> {
+    (0..num_vars).filter(|c| !pivot_cols.contains(c)).collect()
+}
+
+/// Why a machine's fewest presses are unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unsolved {
+    NoSolution,
+    NodeLimit,
+    TimedOut,
+}
+
+/// The sum of every machine's presses, or which machines have none and why.
+fn total_or_unsolved(presses: &[Result<i64, Unsolved>]) -> Answer {
+    let machines_where = |reason: Unsolved| -> Vec<String> {
+        presses
+            .iter()
+            .enumerate()
+            .filter(|&(_, p)| *p == Err(reason))
+            .map(|(i, _)| (i + 1).to_string())
+            .collect()
+    };
+
+    let reasons: Vec<String> = [
+        (Unsolved::NoSolution, "no solution for"),
+        (Unsolved::NodeLimit, "node limit reached on"),
+        (Unsolved::TimedOut, "timed out on"),
+    ]
+    .into_iter()
+    .filter_map(|(reason, what)| {
+        let machines = machines_where(reason);
+        match machines.len() {
+            0 => None,
+            1 => Some(format!("{what} machine {}", machines[0])),
+            _ => Some(format!("{what} machines {}", machines.join(", "))),
+        }
+    })
+    .collect();
+
+    if reasons.is_empty() {
+        presses.iter().flatten().sum::<i64>().into()
+    } else {
+        Answer::Text(format!("unknown: {}", reasons.join("; ")))
+    }
+}
+
+/// The `i`th machine's joltage plan, with the nodes it took to find.
+fn explain_joltages(i: usize, machine: &Machine) -> String {
+    let report = machine.joltage_report(NODE_LIMIT);
+    let nodes = match report.nodes {
+        1 => "1 node".to_string(),
+        n => format!("{n} nodes"),
+    };
+    let plan = |optimum: &ilp::Optimum| {
+        format!(
+            "{} presses: {}",
+            optimum.total,
+            machine.describe(&optimum.values)
+        )
+    };
+
+    match &report.outcome {
+        ilp::Outcome::Optimal(optimum) => format!(
+            "Machine {}: {} (optimal after {})",
+            i + 1,
+            plan(optimum),
+            nodes
+        ),
+        ilp::Outcome::Infeasible => format!("Machine {}: no solution (after {})", i + 1, nodes),
+        ilp::Outcome::NodeLimit(best) | ilp::Outcome::TimedOut(best) => {
+            let best = best.as_ref().map_or("none found".to_string(), plan);
+            format!("Machine {}: gave up after {}; best {best}", i + 1, nodes)
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Factory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Factory::new(input)
+    }
+
+    fn part1(factory: &Self::Input) -> Answer {
+        let mut presses = Vec::new();
+        for machine in factory.machines() {
+            match machine.min_presses() {
+                Ok(found) => presses.push(found.map(|p| p as i64).ok_or(Unsolved::NoSolution)),
+                Err(e) => return Answer::Text(e.to_string()),
+            }
+        }
+        total_or_unsolved(&presses)
+    }
+
+    fn part2(factory: &Self::Input) -> Answer {
+        Self::part2_with(factory, &BatchOptions::default())
+    }
+
+    fn part2_with(factory: &Self::Input, options: &BatchOptions) -> Answer {
+        let presses: Vec<Result<i64, Unsolved>> = factory
+            .joltage_reports_with(&Batch::with_options("machines", *options))
+            .into_iter()
+            .map(|report| match report.outcome {
+                ilp::Outcome::Optimal(optimum) => Ok(optimum.total),
+                ilp::Outcome::Infeasible => Err(Unsolved::NoSolution),
+                ilp::Outcome::NodeLimit(_) => Err(Unsolved::NodeLimit),
+                ilp::Outcome::TimedOut(_) => Err(Unsolved::TimedOut),
+            })
+            .collect();
+        total_or_unsolved(&presses)
+    }
+
+    fn explain(factory: &Self::Input, part: Part) -> Option<String> {
+        let lines: Vec<String> = factory
+            .machines()
+            .iter()
+            .enumerate()
+            .map(|(i, machine)| {
+                let plan = match part {
+                    Part::Part1 => machine.light_plan().map_err(|e| e.to_string()),
+                    Part::Part2 => return explain_joltages(i, machine),
+                };
+                match plan {
+                    Ok(Some(plan)) => format!(
+                        "Machine {}: {} presses: {}",
+                        i + 1,
+                        plan.iter().sum::<i64>(),
+                        machine.describe(&plan)
+                    ),
+                    Ok(None) => format!("Machine {}: no solution", i + 1),
+                    Err(e) => format!("Machine {}: {e}", i + 1),
+                }
+            })
+            .collect();
+
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ilp::Outcome;
+    use crate::{Day10, Factory, NODE_LIMIT};
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::answers::expected;
+    use aoc_core::{Answer, Solution};
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        assert_eq!(
+            Day10::solve_file("./test.txt", Part1),
+            expected(10, "test", Part1)
+        );
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        assert_eq!(
+            Day10::solve_file("./input.txt", Part1),
+            expected(10, "input", Part1)
+        );
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        assert_eq!(
+            Day10::solve_file("./test.txt", Part2),
+            expected(10, "test", Part2)
+        );
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        assert_eq!(
+            Day10::solve_file("./input.txt", Part2),
+            expected(10, "input", Part2)
+        );
+    }
+
+    #[test]
+    fn reports_location_of_invalid_indicator() {
+        let input = "[.##.] (3) (1,3) {3,5,4,7}\n[#.?] (0) (1) {1,2}";
+        let error = Day10::parse(input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.snippet, "?");
+    }
+
+    #[test]
+    fn plans_reach_their_targets() {
+        let factory = Factory::new(include_str!("../test.txt")).unwrap();
+
+        for machine in factory.machines() {
+            let lights = machine.light_plan().unwrap().unwrap();
+            assert!(machine.lights_reached(&lights), "{lights:?}");
+
+            let joltages = machine.joltage_plan().unwrap();
+            assert!(machine.joltages_reached(&joltages), "{joltages:?}");
+        }
+    }
+
+    #[test]
+    fn simulator_rejects_wrong_plans() {
+        let factory = Factory::new("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        let machine = &factory.machines()[0];
+
+        assert!(machine.lights_reached(&[0, 0, 0, 0, 1, 1]));
+        assert!(!machine.lights_reached(&[1, 0, 0, 0, 1, 1]));
+        assert!(!machine.lights_reached(&[0, 0, 0, 0, 1]));
+        assert_eq!(machine.simulate_joltages(&[1, 3, 0, 3, 1, 2]), [3, 5, 4, 7]);
+        assert!(!machine.joltages_reached(&[1, 3, 0, 3, 1, 1]));
+    }
+
+    #[test]
+    fn reports_machines_with_too_many_free_buttons() {
+        let buttons = "(0) ".repeat(40);
+        let factory = Day10::parse(&format!("[#] {buttons}{{1}}")).unwrap();
+
+        assert_eq!(
+            Day10::part1(&factory),
+            Answer::Text(
+                "39 free buttons is too many to search; at most 32 are supported".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn sets_lights_from_their_starting_pattern() {
+        let factory =
+            Factory::new("[#..#>.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        let machine = &factory.machines()[0];
+        let plan = machine.light_plan().unwrap().unwrap();
+
+        assert!(machine.lights_reached(&plan));
+        // Toggling every light: (1,3) (0,2) does it in two.
+        assert_eq!(machine.min_presses(), Ok(Some(2)));
+    }
+
+    #[test]
+    fn cycles_multi_state_lights() {
+        let factory =
+            Factory::new("[0120>2001]%3 (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        let machine = &factory.machines()[0];
+        let plan = machine.light_plan().unwrap().unwrap();
+
+        assert_eq!(machine.light_states(), 3);
+        assert!(machine.lights_reached(&plan));
+
+        let fewest = (0..3i64.pow(6))
+            .map(|n| (0..6).map(|b| n / 3i64.pow(b) % 3).collect::<Vec<_>>())
+            .filter(|candidate| machine.lights_reached(candidate))
+            .map(|candidate| candidate.iter().sum::<i64>())
+            .min();
+        assert_eq!(Some(plan.iter().sum()), fewest);
+    }
+
+    #[test]
+    fn reports_invalid_light_patterns() {
+        let cases = [
+            (
+                "[#.>.##.] (0) {1}",
+                (1, 2),
+                "expected a starting pattern of 4 lights, found 2",
+            ),
+            (
+                "[0130]%3 (0) {1}",
+                (1, 4),
+                "expected a light state below 3, found `3`",
+            ),
+            (
+                "[01]%1 (0) {1}",
+                (1, 6),
+                "expected between 2 and 10 light states",
+            ),
+        ];
+
+        for (input, position, message) in cases {
+            let error = Day10::parse(input).unwrap_err();
+            assert_eq!((error.line, error.column), position, "{input}");
+            assert_eq!(error.message, message, "{input}");
+        }
+    }
+
+    #[test]
+    fn explains_each_machine() {
+        let factory = Day10::parse(include_str!("../test.txt")).unwrap();
+        let explanation = Day10::explain(&factory, Part1).unwrap();
+
+        assert_eq!(
+            explanation.lines().next(),
+            Some("Machine 1: 2 presses: (0,2) (0,1)")
+        );
+        assert_eq!(explanation.lines().count(), 3);
+
+        let explanation = Day10::explain(&factory, Part2).unwrap();
+        assert_eq!(
+            explanation.lines().next(),
+            Some("Machine 1: 10 presses: (3) (1,3) x5 (2,3) (0,2) x3 (optimal after 1 node)")
+        );
+    }
+
+    #[test]
+    fn names_the_machines_without_an_answer() {
+        let factory = Day10::parse("[#] (0) {2}\n[.] (0) {3}\n[#] {1}").unwrap();
+
+        assert_eq!(
+            Day10::part1(&factory),
+            Answer::Text("unknown: no solution for machine 3".to_string())
+        );
+        assert_eq!(
+            Day10::part2(&factory),
+            Answer::Text("unknown: no solution for machine 3".to_string())
+        );
+    }
+
+    #[test]
+    fn proves_every_example_machine_optimal() {
+        let factory = Factory::new(include_str!("../test.txt")).unwrap();
+        let totals: Vec<_> = factory
+            .joltage_reports(NODE_LIMIT)
+            .into_iter()
+            .map(|report| match report.outcome {
+                Outcome::Optimal(optimum) => optimum.total,
+                outcome => panic!("expected an optimum, got {outcome:?}"),
+            })
+            .collect();
+
+        assert_eq!(totals, [10, 12, 11]);
+    }
+}
@@ -0,0 +1,262 @@
+//! Linear algebra over Z/kZ, for lights that cycle through `k` states.
+//!
+//! `k` need not be prime, so not every pivot can be inverted. Elimination
+//! instead combines rows with extended-gcd steps, which are invertible and
+//! keep the solution set intact, and the search then tries every value of
+//! each pivot variable that satisfies its row.
+
+use crate::TooManyFreeVariables;
+
+/// The most assignments of free variables [`find_min_solution`] will try:
+/// `2^20`, the size of the largest table the GF(2) search keeps, so any
+/// machine either search accepts finishes in well under a second.
+const MAX_ASSIGNMENTS: u64 = 1 << 20;
+
+/// Reduces `matrix` (with the augmented column last) to row echelon form
+/// mod `k` in its first `num_cols` columns, returning each row's pivot column.
+pub fn eliminate(matrix: &mut [Vec<u32>], num_cols: usize, k: u32) -> Vec<usize> {
+    let mut pivot_cols = Vec::new();
+    let mut pivot_row = 0;
+
+    for col in 0..num_cols {
+        if pivot_row == matrix.len() {
+            break;
+        }
+
+        for row in pivot_row + 1..matrix.len() {
+            if matrix[row][col] != 0 {
+                combine(matrix, pivot_row, row, col, k);
+            }
+        }
+
+        if matrix[pivot_row][col] != 0 {
+            pivot_cols.push(col);
+            pivot_row += 1;
+        }
+    }
+
+    pivot_cols
+}
+
+/// Replaces rows `top` and `bottom` with combinations that leave
+/// `gcd(top[col], bottom[col])` in `top` and zero in `bottom`.
+fn combine(matrix: &mut [Vec<u32>], top: usize, bottom: usize, col: usize, k: u32) {
+    let (a, b) = (i64::from(matrix[top][col]), i64::from(matrix[bottom][col]));
+    let (g, s, t) = extended_gcd(a, b);
+    let k = i64::from(k);
+
+    for c in 0..matrix[top].len() {
+        let (x, y) = (i64::from(matrix[top][c]), i64::from(matrix[bottom][c]));
+        matrix[top][c] = (s * x + t * y).rem_euclid(k) as u32;
+        matrix[bottom][c] = (b / g * x - a / g * y).rem_euclid(k) as u32;
+    }
+}
+
+/// `(g, s, t)` with `g = gcd(a, b) = s * a + t * b`.
+fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, s, t) = extended_gcd(b, a % b);
+        (g, t, s - a / b * t)
+    }
+}
+
+pub fn is_consistent(matrix: &[Vec<u32>], num_pivots: usize, augment_col: usize) -> bool {
+    matrix
+        .iter()
+        .skip(num_pivots)
+        .all(|row| row[augment_col] == 0)
+}
+
+/// The solution, with every value in `0..k`, with the smallest sum.
+///
+/// Expects `matrix` in the form left by [`eliminate`].
+pub fn find_min_solution(
+    matrix: &[Vec<u32>],
+    pivot_cols: &[usize],
+    num_vars: usize,
+    k: u32,
+) -> Result<Option<Vec<u32>>, TooManyFreeVariables> {
+    let rows = &matrix[..pivot_cols.len()];
+    // Free variables no equation mentions only ever add presses.
+    let free = (0..num_vars)
+        .filter(|c| !pivot_cols.contains(c) && rows.iter().any(|row| row[*c] != 0))
+        .count();
+
+    let max = max_free_variables(k);
+    if free > max {
+        return Err(TooManyFreeVariables { free, max });
+    }
+
+    let mut search = Search {
+        rows,
+        pivot_cols,
+        k,
+        values: vec![0; num_vars],
+        best: None,
+    };
+    search.assign(num_vars, 0);
+
+    Ok(search.best.map(|(_, values)| values))
+}
+
+/// The most free variables whose `k^free` assignments fit in [`MAX_ASSIGNMENTS`].
+fn max_free_variables(k: u32) -> usize {
+    let mut free = 0;
+    let mut assignments = 1u64;
+    while assignments * u64::from(k) <= MAX_ASSIGNMENTS {
+        assignments *= u64::from(k);
+        free += 1;
+    }
+    free
+}
+
+struct Search<'a> {
+    rows: &'a [Vec<u32>],
+    pivot_cols: &'a [usize],
+    k: u32,
+    values: Vec<u32>,
+    best: Option<(u32, Vec<u32>)>,
+}
+
+impl Search<'_> {
+    /// Assigns the variables before `col` from right to left, so every
+    /// pivot row has all its later variables fixed when its turn comes.
+    fn assign(&mut self, col: usize, sum: u32) {
+        if self.best.as_ref().is_some_and(|(best, _)| sum >= *best) {
+            return;
+        }
+
+        let Some(col) = col.checked_sub(1) else {
+            self.best = Some((sum, self.values.clone()));
+            return;
+        };
+
+        let candidates: Vec<u32> = match self.pivot_cols.iter().position(|&p| p == col) {
+            Some(row) => self.pivot_values(row, col),
+            None if self.rows.iter().all(|row| row[col] == 0) => vec![0],
+            None => (0..self.k).collect(),
+        };
+
+        for value in candidates {
+            self.values[col] = value;
+            self.assign(col, sum + value);
+        }
+        self.values[col] = 0;
+    }
+
+    /// The values of the pivot variable `col` that satisfy `row`.
+    fn pivot_values(&self, row: usize, col: usize) -> Vec<u32> {
+        let k = u64::from(self.k);
+        let equation = &self.rows[row];
+        let num_vars = self.values.len();
+
+        let known = (col + 1..num_vars)
+            .map(|c| u64::from(equation[c]) * u64::from(self.values[c]))
+            .sum::<u64>();
+        let target = (u64::from(equation[num_vars]) + k - known % k) % k;
+        let pivot = u64::from(equation[col]);
+
+        (0..self.k)
+            .filter(|&x| pivot * u64::from(x) % k == target)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn solve(mut matrix: Vec<Vec<u32>>, k: u32) -> Option<Vec<u32>> {
+        let num_vars = matrix[0].len() - 1;
+        let pivot_cols = eliminate(&mut matrix, num_vars, k);
+        if !is_consistent(&matrix, pivot_cols.len(), num_vars) {
+            return None;
+        }
+        find_min_solution(&matrix, &pivot_cols, num_vars, k).unwrap()
+    }
+
+    #[test]
+    fn solves_over_a_prime_modulus() {
+        // x0 + x1 = 2 and x1 + x2 = 1 (mod 3): (2,0,1) sums to 3, (1,1,0) to 2.
+        let solution = solve(vec![vec![1, 1, 0, 2], vec![0, 1, 1, 1]], 3);
+        assert_eq!(solution, Some(vec![1, 1, 0]));
+    }
+
+    #[test]
+    fn solves_with_non_invertible_pivots() {
+        // 2x = 2 (mod 4) has the solutions 1 and 3; 2x = 1 has none.
+        assert_eq!(solve(vec![vec![2, 2]], 4), Some(vec![1]));
+        assert_eq!(solve(vec![vec![2, 1]], 4), None);
+        // Together, 2x = 2 and 3x = 3 rule out x = 3.
+        assert_eq!(solve(vec![vec![2, 2], vec![3, 3]], 4), Some(vec![1]));
+    }
+
+    #[test]
+    fn limits_the_search_by_modulus() {
+        assert_eq!(max_free_variables(2), 20);
+        assert_eq!(max_free_variables(3), 12);
+        assert_eq!(max_free_variables(10), 6);
+    }
+
+    #[test]
+    fn searches_the_most_free_variables_each_modulus_allows() {
+        // One light wired to every button leaves all but one free, and the
+        // fewest presses lean on a single button or spread across several.
+        for (k, target, presses) in [(2, 1, 1), (3, 2, 2), (10, 9, 9)] {
+            let num_vars = max_free_variables(k) + 1;
+            let mut row = vec![1; num_vars];
+            row.push(target);
+            let solution = solve(vec![row.clone()], k).unwrap();
+            assert_eq!(solution.iter().sum::<u32>(), presses, "mod {k}");
+
+            row.insert(0, 1);
+            let mut matrix = vec![row];
+            let pivot_cols = eliminate(&mut matrix, num_vars + 1, k);
+            assert_eq!(
+                find_min_solution(&matrix, &pivot_cols, num_vars + 1, k),
+                Err(TooManyFreeVariables {
+                    free: num_vars,
+                    max: num_vars - 1
+                })
+            );
+        }
+    }
+
+    /// The smallest-sum solution found by trying every assignment in `0..k`.
+    fn brute_force(matrix: &[Vec<u32>], k: u32) -> Option<u32> {
+        let num_vars = matrix[0].len() - 1;
+        (0..k.pow(num_vars as u32))
+            .map(|n| {
+                (0..num_vars as u32)
+                    .map(|v| n / k.pow(v) % k)
+                    .collect::<Vec<_>>()
+            })
+            .filter(|values| {
+                matrix.iter().all(|row| {
+                    let sum: u32 = row.iter().zip(values).map(|(a, x)| a * x).sum();
+                    sum % k == row[num_vars]
+                })
+            })
+            .map(|values| values.iter().sum())
+            .min()
+    }
+
+    fn system() -> impl Strategy<Value = (Vec<Vec<u32>>, u32)> {
+        (2..=6u32, 1..=3usize, 1..=4usize).prop_flat_map(|(k, rows, cols)| {
+            let row = prop::collection::vec(0..k, cols + 1);
+            (prop::collection::vec(row, rows), Just(k))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force((matrix, k) in system()) {
+            let expected = brute_force(&matrix, k);
+            let solution = solve(matrix, k);
+            prop_assert_eq!(solution.map(|values| values.iter().sum()), expected);
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Drawing the device graph as Graphviz DOT or as a Mermaid flowchart, to
+//! see how the devices on a query's paths connect.
+
+use crate::{Cycle, Server};
+use num_bigint::BigUint;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Fill colour for highlighted devices.
+const HIGHLIGHT: &str = "#ffd54f";
+
+/// Edge colours from fewest paths to most, on a log scale.
+const PALETTE: [&str; 4] = ["#4575b4", "#91bfdb", "#fc8d59", "#d73027"];
+
+/// Edge colour for cables no path uses.
+const UNUSED: &str = "#bdbdbd";
+
+#[derive(Debug, Clone, Default)]
+pub struct Export<'a> {
+    highlight: Vec<&'a str>,
+    path_counts: Option<(&'a str, &'a str)>,
+    prune: Option<(&'a str, &'a str)>,
+}
+
+impl<'a> Export<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fills in `devices` so they stand out.
+    pub fn highlight(mut self, devices: impl IntoIterator<Item = &'a str>) -> Self {
+        self.highlight.extend(devices);
+        self
+    }
+
+    /// Labels and colours each cable by how many paths from `from` to `to`
+    /// use it. Needs the cables between them to be acyclic.
+    pub fn colour_by_paths(mut self, from: &'a str, to: &'a str) -> Self {
+        self.path_counts = Some((from, to));
+        self
+    }
+
+    /// Only draws the devices on some path from `from` to `to`.
+    pub fn prune(mut self, from: &'a str, to: &'a str) -> Self {
+        self.prune = Some((from, to));
+        self
+    }
+}
+
+/// The devices and cables to draw, after pruning.
+struct Drawing {
+    devices: Vec<usize>,
+    highlighted: HashSet<usize>,
+    cables: Vec<(usize, usize, Option<BigUint>)>,
+    most_paths: BigUint,
+}
+
+impl Drawing {
+    /// The colour for a cable used by `count` paths.
+    fn colour(&self, count: &BigUint) -> &'static str {
+        if count.bits() == 0 {
+            return UNUSED;
+        }
+        let level = (count.bits() * PALETTE.len() as u64 - 1) / self.most_paths.bits();
+        PALETTE[level as usize]
+    }
+}
+
+impl Server {
+    /// The graph in Graphviz DOT, laid out left to right.
+    pub fn to_dot(&self, export: &Export) -> Result<String, Cycle> {
+        let drawing = self.drawing(export)?;
+        let quote = |id: usize| {
+            format!(
+                "\"{}\"",
+                self.name(id).replace('\\', "\\\\").replace('"', "\\\"")
+            )
+        };
+
+        let mut dot = String::from("digraph server {\n    rankdir=LR;\n");
+        for &device in &drawing.devices {
+            if drawing.highlighted.contains(&device) {
+                let _ = writeln!(
+                    dot,
+                    "    {} [style=filled, fillcolor=\"{HIGHLIGHT}\"];",
+                    quote(device)
+                );
+            } else {
+                let _ = writeln!(dot, "    {};", quote(device));
+            }
+        }
+        for (from, to, count) in &drawing.cables {
+            let _ = match count {
+                Some(count) => writeln!(
+                    dot,
+                    "    {} -> {} [label=\"{count}\", color=\"{}\"];",
+                    quote(*from),
+                    quote(*to),
+                    drawing.colour(count)
+                ),
+                None => writeln!(dot, "    {} -> {};", quote(*from), quote(*to)),
+            };
+        }
+        dot.push_str("}\n");
+
+        Ok(dot)
+    }
+
+    /// The graph as a Mermaid flowchart, laid out left to right.
+    pub fn to_mermaid(&self, export: &Export) -> Result<String, Cycle> {
+        let drawing = self.drawing(export)?;
+
+        let mut mermaid = String::from("flowchart LR\n");
+        for &device in &drawing.devices {
+            let label = self.name(device).replace('"', "#quot;");
+            let _ = writeln!(mermaid, "    d{device}[\"{label}\"]");
+        }
+        for (from, to, count) in &drawing.cables {
+            let _ = match count {
+                Some(count) => writeln!(mermaid, "    d{from} -->|{count}| d{to}"),
+                None => writeln!(mermaid, "    d{from} --> d{to}"),
+            };
+        }
+
+        let highlighted: Vec<String> = drawing
+            .devices
+            .iter()
+            .filter(|device| drawing.highlighted.contains(device))
+            .map(|device| format!("d{device}"))
+            .collect();
+        if !highlighted.is_empty() {
+            let _ = writeln!(mermaid, "    classDef highlight fill:{HIGHLIGHT}");
+            let _ = writeln!(mermaid, "    class {} highlight", highlighted.join(","));
+        }
+        // Mermaid styles links by their position in the chart.
+        for (link, (_, _, count)) in drawing.cables.iter().enumerate() {
+            if let Some(count) = count {
+                let _ = writeln!(
+                    mermaid,
+                    "    linkStyle {link} stroke:{}",
+                    drawing.colour(count)
+                );
+            }
+        }
+
+        Ok(mermaid)
+    }
+
+    fn drawing(&self, export: &Export) -> Result<Drawing, Cycle> {
+        let kept: Vec<bool> = match export.prune {
+            Some((from, to)) => self.between_names(from, to),
+            None => vec![true; self.names.len()],
+        };
+
+        let counts = match export.path_counts {
+            Some((from, to)) => Some(self.cable_counts(from, to)?),
+            None => None,
+        };
+
+        let devices: Vec<usize> = (0..self.names.len()).filter(|&id| kept[id]).collect();
+        let mut cables = Vec::new();
+        for &from in &devices {
+            for (i, &to) in self.outputs[from].iter().enumerate() {
+                if kept[to] {
+                    let count = counts.as_ref().map(|counts| counts[from][i].clone());
+                    cables.push((from, to, count));
+                }
+            }
+        }
+
+        let most_paths = cables
+            .iter()
+            .filter_map(|(_, _, count)| count.clone())
+            .max()
+            .unwrap_or_default();
+        let highlighted = export
+            .highlight
+            .iter()
+            .filter_map(|&name| self.id(name))
+            .collect();
+
+        Ok(Drawing {
+            devices,
+            highlighted,
+            cables,
+            most_paths,
+        })
+    }
+
+    /// Which devices lie on some path from `from` to `to`; none if either
+    /// does not exist.
+    fn between_names(&self, from: &str, to: &str) -> Vec<bool> {
+        match (self.id(from), self.id(to)) {
+            (Some(from), Some(to)) => self.between(from, to, |_| false),
+            _ => vec![false; self.names.len()],
+        }
+    }
+
+    /// For each cable, indexed like `outputs`, the number of paths from
+    /// `from` to `to` that use it: the paths into its start times the
+    /// paths out of its end.
+    fn cable_counts(&self, from: &str, to: &str) -> Result<Vec<Vec<BigUint>>, Cycle> {
+        let order = match (self.id(from), self.id(to)) {
+            (Some(from), Some(to)) => self.topological_order_between(from, to, |_| false)?,
+            _ => Vec::new(),
+        };
+        let mut into = vec![BigUint::default(); self.names.len()];
+        let mut out_of = vec![BigUint::default(); self.names.len()];
+
+        if let (Some(from), Some(to)) = (self.id(from), self.id(to)) {
+            into[from] = 1u8.into();
+            for &device in &order {
+                if device != to {
+                    for &output in &self.outputs[device] {
+                        into[output] = &into[output] + &into[device];
+                    }
+                }
+            }
+
+            out_of[to] = 1u8.into();
+            for &device in order.iter().rev() {
+                if device != to {
+                    out_of[device] = self.outputs[device].iter().map(|&o| &out_of[o]).sum();
+                }
+            }
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(device, outputs)| {
+                outputs
+                    .iter()
+                    .map(|&output| &into[device] * &out_of[output])
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Server {
+        Server::new(include_str!("../test2.txt")).unwrap()
+    }
+
+    #[test]
+    fn draws_dot_with_highlights() {
+        let server = Server::new("you: a b\na: out\nb: out\n").unwrap();
+        let dot = server
+            .to_dot(&Export::new().highlight(["you", "out"]))
+            .unwrap();
+
+        assert_eq!(
+            dot,
+            "digraph server {
+    rankdir=LR;
+    \"you\" [style=filled, fillcolor=\"#ffd54f\"];
+    \"a\";
+    \"b\";
+    \"out\" [style=filled, fillcolor=\"#ffd54f\"];
+    \"you\" -> \"a\";
+    \"you\" -> \"b\";
+    \"a\" -> \"out\";
+    \"b\" -> \"out\";
+}
+"
+        );
+    }
+
+    #[test]
+    fn draws_mermaid_pruned_and_coloured_by_paths() {
+        let export = Export::new()
+            .highlight(["fft"])
+            .prune("fft", "dac")
+            .colour_by_paths("fft", "dac");
+
+        assert_eq!(
+            example().to_mermaid(&export).unwrap(),
+            "flowchart LR
+    d3[\"fft\"]
+    d4[\"ccc\"]
+    d7[\"eee\"]
+    d10[\"dac\"]
+    d3 -->|1| d4
+    d4 -->|1| d7
+    d7 -->|1| d10
+    classDef highlight fill:#ffd54f
+    class d3 highlight
+    linkStyle 0 stroke:#d73027
+    linkStyle 1 stroke:#d73027
+    linkStyle 2 stroke:#d73027
+"
+        );
+    }
+
+    #[test]
+    fn counts_the_paths_through_each_cable() {
+        let server = Server::new(include_str!("../test.txt")).unwrap();
+        let dot = server
+            .to_dot(&Export::new().colour_by_paths("you", "out"))
+            .unwrap();
+
+        assert!(dot.contains("\"you\" -> \"ccc\" [label=\"3\", color=\"#d73027\"];"));
+        assert!(dot.contains("\"ccc\" -> \"fff\" [label=\"1\", color=\"#91bfdb\"];"));
+        assert!(dot.contains("\"aaa\" -> \"you\" [label=\"0\", color=\"#bdbdbd\"];"));
+
+        let cyclic = Server::new("a: b\nb: a c\nc: d\n").unwrap();
+        assert!(cyclic.to_dot(&Export::new()).is_ok());
+        assert!(
+            cyclic
+                .to_dot(&Export::new().colour_by_paths("a", "c"))
+                .is_err()
+        );
+        assert!(
+            cyclic
+                .to_dot(&Export::new().colour_by_paths("c", "d"))
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Checks on the shape of the device graph: a topological order of the
+//! devices, the cycles that prevent one, and suspicious wiring.
+
+use crate::Server;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// A loop in the cables, listed from a device back round to itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub devices: Vec<String>,
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the cables loop: {}", self.devices.join(" -> "))
+    }
+}
+
+impl std::error::Error for Cycle {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// A device outputs to one that has no line of its own, and so is a dead end.
+    UndefinedTarget { device: String, target: String },
+    /// A device has more than one line; only the last is used.
+    DuplicateDevice(String),
+    /// The cables loop, so paths through the loop cannot be counted.
+    Cycle(Cycle),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::UndefinedTarget { device, target } => write!(
+                f,
+                "`{device}` outputs to `{target}`, which is not defined, so no path continues past it"
+            ),
+            Warning::DuplicateDevice(device) => write!(
+                f,
+                "`{device}` is defined more than once; only its last line is used"
+            ),
+            Warning::Cycle(cycle) => write!(
+                f,
+                "{cycle}, so paths through those devices cannot be counted"
+            ),
+        }
+    }
+}
+
+impl Server {
+    /// Anything that looks wrong with the graph. `sinks` are the devices
+    /// expected to have no outputs.
+    ///
+    /// A loop only stops the queries whose paths could go round it, so it
+    /// is a warning here and an error from those queries.
+    pub fn validate(&self, sinks: &[&str]) -> Vec<Warning> {
+        let mut warnings: Vec<Warning> = self
+            .topological_order()
+            .err()
+            .map(Warning::Cycle)
+            .into_iter()
+            .collect();
+        warnings.extend(
+            self.duplicates
+                .iter()
+                .map(|&id| Warning::DuplicateDevice(self.name(id).to_string())),
+        );
+
+        for (device, outputs) in self.outputs.iter().enumerate() {
+            for &target in outputs {
+                if !self.defined[target] && !sinks.contains(&self.name(target)) {
+                    warnings.push(Warning::UndefinedTarget {
+                        device: self.name(device).to_string(),
+                        target: self.name(target).to_string(),
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Every device, each before all the devices it outputs to.
+    pub fn devices_in_order(&self) -> Result<Vec<&str>, Cycle> {
+        Ok(self
+            .topological_order()?
+            .into_iter()
+            .map(|id| self.name(id))
+            .collect())
+    }
+
+    pub(crate) fn topological_order(&self) -> Result<Vec<usize>, Cycle> {
+        self.order_cables(|_, _| true)
+    }
+
+    /// The devices on some path from `from` to `to` that goes through none
+    /// of the `avoided` ones, each before the others it outputs to. Paths
+    /// end at `to`, so its own outputs are left out.
+    pub(crate) fn topological_order_between(
+        &self,
+        from: usize,
+        to: usize,
+        avoided: impl Fn(usize) -> bool,
+    ) -> Result<Vec<usize>, Cycle> {
+        let kept = self.between(from, to, avoided);
+        let order =
+            self.order_cables(|device, target| kept[device] && kept[target] && device != to)?;
+        Ok(order.into_iter().filter(|&id| kept[id]).collect())
+    }
+
+    /// Every device, each before all the devices it outputs to along the
+    /// cables `counted` keeps, by Kahn's algorithm. Ready devices are taken
+    /// in the order they were first seen so the result is deterministic.
+    fn order_cables(&self, counted: impl Fn(usize, usize) -> bool) -> Result<Vec<usize>, Cycle> {
+        let mut in_degree = vec![0; self.names.len()];
+        for (device, outputs) in self.outputs.iter().enumerate() {
+            for &target in outputs {
+                if counted(device, target) {
+                    in_degree[target] += 1;
+                }
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.names.len())
+            .filter(|&id| in_degree[id] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.names.len());
+
+        while let Some(device) = ready.pop_front() {
+            order.push(device);
+            for &target in &self.outputs[device] {
+                if counted(device, target) {
+                    in_degree[target] -= 1;
+                    if in_degree[target] == 0 {
+                        ready.push_back(target);
+                    }
+                }
+            }
+        }
+
+        if order.len() == self.names.len() {
+            Ok(order)
+        } else {
+            Err(self.find_cycle(counted, &in_degree))
+        }
+    }
+
+    /// Which devices lie on some path from `from` to `to` that goes
+    /// through none of the `avoided` ones. Paths end at `to`, so loops
+    /// that only leave from it are not on any.
+    pub(crate) fn between(
+        &self,
+        from: usize,
+        to: usize,
+        avoided: impl Fn(usize) -> bool,
+    ) -> Vec<bool> {
+        let reachable = self.reachable(from, &avoided, |device| {
+            if device == to {
+                &[][..]
+            } else {
+                &self.outputs[device][..]
+            }
+        });
+        let inputs = self.inputs();
+        let co_reachable = self.reachable(to, &avoided, |device| &inputs[device][..]);
+
+        reachable
+            .iter()
+            .zip(&co_reachable)
+            .map(|(&forward, &back)| forward && back)
+            .collect()
+    }
+
+    /// Which devices can be reached from `start` by following `next`
+    /// without entering an `avoided` one.
+    fn reachable<'a>(
+        &self,
+        start: usize,
+        avoided: impl Fn(usize) -> bool,
+        next: impl Fn(usize) -> &'a [usize],
+    ) -> Vec<bool> {
+        let mut seen = vec![false; self.names.len()];
+        let mut stack: Vec<usize> = [start].into_iter().filter(|&d| !avoided(d)).collect();
+        while let Some(device) = stack.pop() {
+            if !std::mem::replace(&mut seen[device], true) {
+                stack.extend(next(device).iter().filter(|&&d| !avoided(d)));
+            }
+        }
+        seen
+    }
+
+    fn inputs(&self) -> Vec<Vec<usize>> {
+        let mut inputs = vec![Vec::new(); self.names.len()];
+        for (device, outputs) in self.outputs.iter().enumerate() {
+            for &output in outputs {
+                inputs[output].push(device);
+            }
+        }
+        inputs
+    }
+
+    /// Every device Kahn's algorithm could not order has a counted input
+    /// from another such device, so walking those inputs backwards must
+    /// repeat.
+    fn find_cycle(&self, counted: impl Fn(usize, usize) -> bool, in_degree: &[usize]) -> Cycle {
+        let stuck = |id: usize| in_degree[id] > 0;
+
+        let mut inputs = vec![Vec::new(); self.names.len()];
+        for (device, outputs) in self.outputs.iter().enumerate() {
+            for &target in outputs {
+                if counted(device, target) && stuck(device) && stuck(target) {
+                    inputs[target].push(device);
+                }
+            }
+        }
+
+        let start = (0..self.names.len())
+            .find(|&id| stuck(id))
+            .expect("a cycle leaves devices unordered");
+        let mut seen_at = vec![None; self.names.len()];
+        let mut walk = Vec::new();
+        let mut device = start;
+
+        while seen_at[device].is_none() {
+            seen_at[device] = Some(walk.len());
+            walk.push(device);
+            device = inputs[device][0];
+        }
+
+        // The walk went against the cables, so read it back to front,
+        // starting and ending at the device it came back to.
+        let looped = &walk[seen_at[device].unwrap()..];
+        let devices: Vec<String> = [device]
+            .into_iter()
+            .chain(looped[1..].iter().rev().copied())
+            .chain([device])
+            .map(|id| self.name(id).to_string())
+            .collect();
+
+        Cycle { devices }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_devices_before_their_outputs() {
+        let server = Server::new(include_str!("../test.txt")).unwrap();
+        let order = server.devices_in_order().unwrap();
+
+        for line in include_str!("../test.txt").lines() {
+            let (device, outputs) = line.split_once(": ").unwrap();
+            let position = |name| order.iter().position(|&d| d == name).unwrap();
+            for output in outputs.split_whitespace() {
+                assert!(position(device) < position(output), "{device} -> {output}");
+            }
+        }
+    }
+
+    #[test]
+    fn reports_the_devices_in_a_cycle() {
+        let server = Server::new("start: a\na: b\nb: c d\nc: a\nd: out\n").unwrap();
+        let cycle = server.topological_order().unwrap_err();
+
+        assert_eq!(cycle.devices, ["a", "b", "c", "a"]);
+        assert_eq!(cycle.to_string(), "the cables loop: a -> b -> c -> a");
+        assert_eq!(server.validate(&["out"]), [Warning::Cycle(cycle)]);
+    }
+
+    #[test]
+    fn orders_only_the_devices_between_two_others() {
+        let server = Server::new("you: a\na: out\nx: y\ny: x\nout: you\n").unwrap();
+        let id = |name| server.id(name).unwrap();
+        let order = server.topological_order_between(id("you"), id("out"), |_| false);
+
+        let names: Vec<&str> = order.unwrap().into_iter().map(|i| server.name(i)).collect();
+        assert_eq!(names, ["you", "a", "out"]);
+        assert!(server.topological_order().is_err());
+
+        let avoiding_a =
+            server.topological_order_between(id("you"), id("out"), |device| device == id("a"));
+        assert_eq!(avoiding_a, Ok(vec![]));
+    }
+
+    #[test]
+    fn warns_about_undefined_targets_and_duplicates() {
+        let server = Server::new("you: a out\na: b\nyou: a\n").unwrap();
+
+        assert_eq!(
+            server.validate(&["out"]),
+            [
+                Warning::DuplicateDevice("you".to_string()),
+                Warning::UndefinedTarget {
+                    device: "a".to_string(),
+                    target: "b".to_string()
+                },
+            ]
+        );
+    }
+}
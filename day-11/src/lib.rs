@@ -0,0 +1,235 @@
+use aoc_core::{Answer, GraphFormat, ParseError, Part, Solution, split_once_at};
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use std::collections::HashMap;
+
+pub mod export;
+pub mod graph;
+pub mod query;
+
+pub use export::Export;
+pub use graph::{Cycle, Warning};
+pub use query::{PathQuery, QueryError};
+
+/// The devices and the cables between them, with each device name interned
+/// as an index into `names`.
+#[derive(Debug)]
+pub struct Server {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    outputs: Vec<Vec<usize>>,
+    /// Whether each device has a line of its own.
+    defined: Vec<bool>,
+    /// Devices with more than one line, in the order first repeated.
+    duplicates: Vec<usize>,
+}
+
+impl Server {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut server = Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            outputs: Vec::new(),
+            defined: Vec::new(),
+            duplicates: Vec::new(),
+        };
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let (id, outputs_str) = split_once_at(input, line, ": ")?;
+            let device = server.intern(id);
+            if server.defined[device] {
+                if !server.duplicates.contains(&device) {
+                    server.duplicates.push(device);
+                }
+            } else {
+                server.defined[device] = true;
+            }
+            let outputs = outputs_str
+                .split_whitespace()
+                .map(|output| server.intern(output))
+                .collect();
+            server.outputs[device] = outputs;
+        }
+
+        Ok(server)
+    }
+
+    fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.outputs.push(Vec::new());
+        self.defined.push(false);
+        id
+    }
+
+    fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+}
+
+/// Counts that outgrow an `i128` are given as text.
+fn count_answer(count: BigUint) -> Answer {
+    match count.to_i128() {
+        Some(n) => Answer::Number(n),
+        None => Answer::Text(count.to_string()),
+    }
+}
+
+/// The start, end and required devices of the paths each part counts.
+fn devices(part: Part) -> (&'static str, &'static str, &'static [&'static str]) {
+    match part {
+        Part::Part1 => ("you", "out", &[]),
+        Part::Part2 => ("svr", "out", &["dac", "fft"]),
+    }
+}
+
+fn query(part: Part) -> PathQuery<'static> {
+    let (from, to, required) = devices(part);
+    PathQuery::new(from, to).through(required.iter().copied())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Server;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Server::new(input)
+    }
+
+    fn part1(server: &Self::Input) -> Answer {
+        match server.count(&query(Part::Part1)) {
+            Ok(count) => count_answer(count),
+            Err(e) => Answer::Text(e.to_string()),
+        }
+    }
+
+    fn part2(server: &Self::Input) -> Answer {
+        match server.count(&query(Part::Part2)) {
+            Ok(count) => count_answer(count),
+            Err(e) => Answer::Text(e.to_string()),
+        }
+    }
+
+    /// An error if the part's paths cannot be counted, then anything else
+    /// wrong with the graph. A loop the part's paths cannot reach is only
+    /// a warning.
+    fn explain(server: &Self::Input, part: Part) -> Option<String> {
+        let error = server.check(&query(part)).err();
+        let mut lines: Vec<String> = error.iter().map(|e| format!("Error: {e}")).collect();
+        lines.extend(
+            server
+                .validate(&["out"])
+                .iter()
+                .filter(|w| error.is_none() || !matches!(w, Warning::Cycle(_)))
+                .map(|w| format!("Warning: {w}")),
+        );
+        if lines.is_empty() {
+            lines.push("The device graph is acyclic".to_string());
+        }
+
+        Some(lines.join("\n"))
+    }
+
+    /// The devices on the part's paths, with its start, end and required
+    /// devices highlighted and each cable coloured by the paths through it.
+    /// A loop leaves the cables uncoloured so it can still be seen.
+    fn graph(server: &Self::Input, part: Part, format: GraphFormat) -> Option<String> {
+        let (from, to, required) = devices(part);
+        let export = Export::new()
+            .highlight([from, to].into_iter().chain(required.iter().copied()))
+            .prune(from, to);
+        let draw = |export: &Export| match format {
+            GraphFormat::Dot => server.to_dot(export),
+            GraphFormat::Mermaid => server.to_mermaid(export),
+        };
+
+        draw(&export.clone().colour_by_paths(from, to))
+            .or_else(|_| draw(&export))
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+    use aoc_core::Part::{Part1, Part2};
+    use aoc_core::answers::expected;
+    use aoc_core::{Answer, GraphFormat, Solution};
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day11::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(11, "test", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day11::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(11, "input", Part1));
+    }
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_2() {
+        let value = Day11::solve_file("./test2.txt", Part2);
+        assert_eq!(value, expected(11, "test2", Part2));
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_2() {
+        let value = Day11::solve_file("./input.txt", Part2);
+        assert_eq!(value, expected(11, "input", Part2));
+    }
+
+    #[test]
+    fn explains_problems_with_the_graph() {
+        let server = Day11::parse(include_str!("../test.txt")).unwrap();
+        assert_eq!(
+            Day11::explain(&server, Part1).as_deref(),
+            Some("The device graph is acyclic")
+        );
+
+        let server = Day11::parse("you: a\na: you out\n").unwrap();
+        assert_eq!(
+            Day11::explain(&server, Part1).as_deref(),
+            Some("Error: the cables loop: you -> a -> you, so there are infinitely many paths")
+        );
+    }
+
+    #[test]
+    fn draws_the_paths_each_part_counts() {
+        let server = Day11::parse(include_str!("../test2.txt")).unwrap();
+        let dot = Day11::graph(&server, Part2, GraphFormat::Dot).unwrap();
+
+        assert!(dot.contains("\"dac\" [style=filled, fillcolor=\"#ffd54f\"];"));
+        assert!(dot.contains("\"svr\" -> \"aaa\" [label=\"4\", color=\"#d73027\"];"));
+        assert!(!dot.contains("\"you\""));
+
+        let looped = Day11::parse("you: a\na: you out\n").unwrap();
+        let mermaid = Day11::graph(&looped, Part1, GraphFormat::Mermaid).unwrap();
+        assert!(mermaid.contains("d1 --> d0"));
+        assert!(!mermaid.contains("linkStyle"));
+    }
+
+    #[test]
+    fn counts_past_loops_off_the_paths() {
+        let server = Day11::parse("you: out\nx: y\ny: x\n").unwrap();
+
+        assert_eq!(Day11::part1(&server), Answer::Number(1));
+        assert_eq!(
+            Day11::explain(&server, Part1).as_deref(),
+            Some(
+                "Warning: the cables loop: x -> y -> x, so paths through those devices cannot be counted"
+            )
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! Counting and listing the paths between two devices, optionally through a
+//! set of required devices (in any order), around a set of forbidden ones,
+//! or within a maximum number of cables.
+//!
+//! Counts are built up iteratively, for each device, by the set of required
+//! devices visited (as a bitmask): in topological order when the length is
+//! unlimited, or layer by layer in the cables left when it is not. Only the
+//! devices on some path from the start to the end are ordered, so a loop
+//! elsewhere in the graph does not stop a query.
+//!
+//! The layers count walks, which may go round a loop. Paths never visit a
+//! device twice, so when a length-limited query's devices do loop, its paths
+//! are counted by searching for them instead, with the layers only pruning
+//! the search.
+//!
+//! Counts are kept in a `u128`, and recounted as a `BigUint` only if that
+//! overflows.
+
+use crate::{Cycle, Server};
+use num_bigint::BigUint;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// The most required devices a query can have, one per bit of a table key.
+pub const MAX_REQUIRED: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct PathQuery<'a> {
+    from: &'a str,
+    to: &'a str,
+    required: Vec<&'a str>,
+    forbidden: Vec<&'a str>,
+    max_length: Option<usize>,
+}
+
+impl<'a> PathQuery<'a> {
+    pub fn new(from: &'a str, to: &'a str) -> Self {
+        Self {
+            from,
+            to,
+            required: Vec::new(),
+            forbidden: Vec::new(),
+            max_length: None,
+        }
+    }
+
+    /// Only paths that visit every one of `devices`, in any order.
+    pub fn through(mut self, devices: impl IntoIterator<Item = &'a str>) -> Self {
+        self.required.extend(devices);
+        self
+    }
+
+    /// Only paths that visit none of `devices`.
+    pub fn avoiding(mut self, devices: impl IntoIterator<Item = &'a str>) -> Self {
+        self.forbidden.extend(devices);
+        self
+    }
+
+    /// Only paths of at most `cables` cables. Paths never visit a device
+    /// twice, so if the devices between the start and the end loop, they
+    /// are counted one by one, which is slow when there are many.
+    pub fn max_length(mut self, cables: usize) -> Self {
+        self.max_length = Some(cables);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    TooManyRequired(usize),
+    /// Without a maximum length, a loop in the cables between the start and
+    /// the end allows infinitely many paths.
+    Cycle(Cycle),
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryError::TooManyRequired(n) => write!(
+                f,
+                "at most {MAX_REQUIRED} required devices are supported, found {n}"
+            ),
+            QueryError::Cycle(cycle) => {
+                write!(f, "{cycle}, so there are infinitely many paths")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl Server {
+    pub fn count(&self, query: &PathQuery) -> Result<BigUint, QueryError> {
+        let Some(plan) = Plan::new(self, query)? else {
+            return Ok(BigUint::default());
+        };
+
+        if plan.max_length.is_some() && plan.order.is_err() {
+            let viable = Tables::<bool>::new(self, &plan).map_err(Stop::query)?;
+            let mut count = 0u128;
+            search(self, &plan, &viable, |_| {
+                count += 1;
+                true
+            });
+            return Ok(count.into());
+        }
+
+        match Tables::<u128>::new(self, &plan) {
+            Ok(tables) => Ok(tables.total(&plan).into()),
+            Err(Stop::Overflow) => match Tables::<BigUint>::new(self, &plan) {
+                Ok(tables) => Ok(tables.total(&plan)),
+                Err(Stop::Overflow) => unreachable!("BigUint cannot overflow"),
+                Err(Stop::Query(e)) => Err(e),
+            },
+            Err(Stop::Query(e)) => Err(e),
+        }
+    }
+
+    /// Whether the query can be answered, without counting its paths.
+    pub fn check(&self, query: &PathQuery) -> Result<(), QueryError> {
+        match Plan::new(self, query)? {
+            Some(Plan {
+                order: Err(cycle),
+                max_length: None,
+                ..
+            }) => Err(QueryError::Cycle(cycle)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Up to `limit` of the matching paths, each listed as its devices in order.
+    pub fn paths(&self, query: &PathQuery, limit: usize) -> Result<Vec<Vec<&str>>, QueryError> {
+        let Some(plan) = Plan::new(self, query)? else {
+            return Ok(Vec::new());
+        };
+
+        let viable = Tables::<bool>::new(self, &plan).map_err(Stop::query)?;
+        let mut paths = Vec::new();
+        if limit > 0 {
+            search(self, &plan, &viable, |path| {
+                paths.push(path.iter().map(|&id| self.name(id)).collect());
+                paths.len() < limit
+            });
+        }
+        Ok(paths)
+    }
+}
+
+/// A device on the path being extended, with the required devices visited
+/// before it, the cables left after reaching it and its next output to try.
+struct Frame {
+    device: usize,
+    visited: u64,
+    remaining: Option<usize>,
+    next_output: usize,
+}
+
+/// Depth-first search for paths, passing each to `found` until it returns
+/// false. Only follows cables the `viable` tables say can still lead to a
+/// match, and never back to a device already on the path.
+fn search(
+    server: &Server,
+    plan: &Plan,
+    viable: &Tables<bool>,
+    mut found: impl FnMut(&[usize]) -> bool,
+) {
+    let mut path = Vec::new();
+    let mut on_path = vec![false; server.names.len()];
+    let mut stack = Vec::new();
+    if viable.any(plan, plan.from, 0, plan.max_length) {
+        path.push(plan.from);
+        on_path[plan.from] = true;
+        stack.push(Frame {
+            device: plan.from,
+            visited: 0,
+            remaining: plan.max_length,
+            next_output: 0,
+        });
+    }
+
+    while let Some(frame) = stack.last_mut() {
+        let device = frame.device;
+        let output = server.outputs[device].get(frame.next_output).copied();
+        frame.next_output += 1;
+        let next = match (output, frame.remaining) {
+            _ if device == plan.to => None,
+            (Some(output), None) => Some((output, None)),
+            (Some(output), Some(remaining)) if remaining > 0 => Some((output, Some(remaining - 1))),
+            _ => None,
+        };
+
+        match next {
+            Some((output, remaining)) => {
+                let visited = frame.visited | plan.bit(device);
+                if !on_path[output] && viable.any(plan, output, visited, remaining) {
+                    path.push(output);
+                    on_path[output] = true;
+                    stack.push(Frame {
+                        device: output,
+                        visited,
+                        remaining,
+                        next_output: 0,
+                    });
+                }
+            }
+            None => {
+                if device == plan.to && !found(&path) {
+                    break;
+                }
+                on_path[device] = false;
+                path.pop();
+                stack.pop();
+            }
+        }
+    }
+}
+
+/// A query resolved to device ids.
+struct Plan {
+    from: usize,
+    to: usize,
+    required: HashMap<usize, u32>,
+    forbidden: HashSet<usize>,
+    max_length: Option<usize>,
+    /// The devices on some path from `from` to `to`, in topological order,
+    /// or a loop among them.
+    order: Result<Vec<usize>, Cycle>,
+}
+
+impl Plan {
+    /// `None` if the query names a start, end or required device that does
+    /// not exist, so no path can match.
+    fn new(server: &Server, query: &PathQuery) -> Result<Option<Self>, QueryError> {
+        let required: HashSet<&str> = query.required.iter().copied().collect();
+        if required.len() > MAX_REQUIRED {
+            return Err(QueryError::TooManyRequired(required.len()));
+        }
+
+        let (Some(from), Some(to)) = (server.id(query.from), server.id(query.to)) else {
+            return Ok(None);
+        };
+        let Some(required) = required
+            .into_iter()
+            .map(|name| server.id(name))
+            .collect::<Option<HashSet<usize>>>()
+        else {
+            return Ok(None);
+        };
+
+        let forbidden: HashSet<usize> = query
+            .forbidden
+            .iter()
+            .filter_map(|&name| server.id(name))
+            .collect();
+        let order =
+            server.topological_order_between(from, to, |device| forbidden.contains(&device));
+
+        Ok(Some(Self {
+            from,
+            to,
+            required: required.into_iter().zip(0..).collect(),
+            forbidden,
+            // No path visits a device twice, so none is longer than this.
+            max_length: query
+                .max_length
+                .map(|cables| cables.min(server.names.len() - 1)),
+            order,
+        }))
+    }
+
+    fn bit(&self, device: usize) -> u64 {
+        self.required.get(&device).map_or(0, |&bit| 1 << bit)
+    }
+
+    fn all_required(&self) -> u64 {
+        match self.required.len() {
+            0 => 0,
+            n => u64::MAX >> (64 - n),
+        }
+    }
+}
+
+/// A path count: `bool` for whether any path exists, `u128` for a count
+/// that may overflow, and `BigUint` for one that cannot.
+trait Tally: Clone {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+impl Tally for bool {
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(*self || *other)
+    }
+}
+
+impl Tally for u128 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u128::checked_add(*self, *other)
+    }
+}
+
+impl Tally for BigUint {
+    fn zero() -> Self {
+        BigUint::default()
+    }
+
+    fn one() -> Self {
+        BigUint::from(1u8)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+enum Stop {
+    Overflow,
+    Query(QueryError),
+}
+
+impl Stop {
+    fn query(self) -> QueryError {
+        match self {
+            Stop::Query(e) => e,
+            Stop::Overflow => unreachable!("bool tallies cannot overflow"),
+        }
+    }
+}
+
+/// Paths from one device to the end, tallied by the required devices they visit.
+type Table<T> = HashMap<u64, T>;
+
+/// A [`Table`] for every device, built without recursion so long chains of
+/// devices cannot overflow the stack.
+struct Tables<T> {
+    /// A single layer without a length limit, or else one per number of
+    /// cables from none up to the limit.
+    layers: Vec<Vec<Table<T>>>,
+}
+
+impl<T: Tally> Tables<T> {
+    fn new(server: &Server, plan: &Plan) -> Result<Self, Stop> {
+        let devices = server.names.len();
+
+        let layers = match plan.max_length {
+            // Every device's outputs come after it, so working back from the
+            // end of the order finds their tables already filled in. Devices
+            // off every path keep empty tables.
+            None => {
+                let order = plan
+                    .order
+                    .as_ref()
+                    .map_err(|cycle| Stop::Query(QueryError::Cycle(cycle.clone())))?;
+                let mut layer = vec![Table::new(); devices];
+                for &device in order.iter().rev() {
+                    layer[device] = Self::table(server, plan, device, &layer)?;
+                }
+                vec![layer]
+            }
+            // Each layer allows one more cable than the one before, so loops
+            // are fine: they only ever reach back to a shorter layer.
+            Some(max_length) => {
+                let mut layers: Vec<Vec<Table<T>>> = Vec::with_capacity(max_length + 1);
+                let none = vec![Table::new(); devices];
+                for _ in 0..=max_length {
+                    let shorter = layers.last().unwrap_or(&none);
+                    let layer = (0..devices)
+                        .map(|device| Self::table(server, plan, device, shorter))
+                        .collect::<Result<_, _>>()?;
+                    layers.push(layer);
+                }
+                layers
+            }
+        };
+
+        Ok(Self { layers })
+    }
+
+    /// The paths from `device`, given the tables of the paths from its outputs.
+    fn table(
+        server: &Server,
+        plan: &Plan,
+        device: usize,
+        outputs: &[Table<T>],
+    ) -> Result<Table<T>, Stop> {
+        let mut table = Table::new();
+        if plan.forbidden.contains(&device) {
+            return Ok(table);
+        }
+
+        let bit = plan.bit(device);
+        if device == plan.to {
+            table.insert(bit, T::one());
+            return Ok(table);
+        }
+
+        for &output in &server.outputs[device] {
+            for (&visited, count) in &outputs[output] {
+                let total = table.entry(visited | bit).or_insert_with(T::zero);
+                *total = total.checked_add(count).ok_or(Stop::Overflow)?;
+            }
+        }
+        Ok(table)
+    }
+
+    fn layer(&self, remaining: Option<usize>) -> &[Table<T>] {
+        &self.layers[remaining.unwrap_or(0)]
+    }
+
+    /// The paths from the start that visit every required device.
+    fn total(&self, plan: &Plan) -> T {
+        self.layer(plan.max_length)[plan.from]
+            .get(&plan.all_required())
+            .cloned()
+            .unwrap_or_else(T::zero)
+    }
+}
+
+impl Tables<bool> {
+    /// Whether some path from `device`, with the required devices in
+    /// `visited` already behind it, completes a match.
+    fn any(&self, plan: &Plan, device: usize, visited: u64, remaining: Option<usize>) -> bool {
+        self.layer(remaining)[device]
+            .keys()
+            .any(|&ahead| visited | ahead == plan.all_required())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `diamonds` diamonds in a row, each doubling the number of paths.
+    fn diamonds(diamonds: usize) -> Server {
+        let input: String = (0..diamonds)
+            .map(|i| {
+                format!(
+                    "n{i}: a{i} b{i}\na{i}: n{next}\nb{i}: n{next}\n",
+                    next = i + 1
+                )
+            })
+            .collect();
+        Server::new(&input).unwrap()
+    }
+
+    fn example() -> Server {
+        Server::new(include_str!("../test2.txt")).unwrap()
+    }
+
+    #[test]
+    fn requires_checkpoints_in_any_order() {
+        let server = example();
+        let both = PathQuery::new("svr", "out").through(["fft", "dac"]);
+
+        assert_eq!(server.count(&both), Ok(2u8.into()));
+        assert_eq!(
+            server.count(&PathQuery::new("svr", "out").through(["dac"])),
+            Ok(4u8.into())
+        );
+        assert_eq!(server.count(&PathQuery::new("svr", "out")), Ok(8u8.into()));
+    }
+
+    #[test]
+    fn avoids_forbidden_devices() {
+        let server = example();
+        let query = PathQuery::new("svr", "out").avoiding(["fft", "ggg"]);
+
+        assert_eq!(server.count(&query), Ok(2u8.into()));
+        for path in server.paths(&query, usize::MAX).unwrap() {
+            assert!(!path.contains(&"fft") && !path.contains(&"ggg"), "{path:?}");
+        }
+    }
+
+    #[test]
+    fn limits_path_length() {
+        let server = diamonds(3);
+
+        assert_eq!(
+            server.count(&PathQuery::new("n0", "n3").max_length(5)),
+            Ok(0u8.into())
+        );
+        assert_eq!(
+            server.count(&PathQuery::new("n0", "n3").max_length(6)),
+            Ok(8u8.into())
+        );
+    }
+
+    #[test]
+    fn lists_the_paths_it_counts() {
+        let server = example();
+        let query = PathQuery::new("svr", "out").through(["dac", "fft"]);
+        let paths = server.paths(&query, usize::MAX).unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                [
+                    "svr", "aaa", "fft", "ccc", "eee", "dac", "fff", "ggg", "out"
+                ],
+                [
+                    "svr", "aaa", "fft", "ccc", "eee", "dac", "fff", "hhh", "out"
+                ],
+            ]
+        );
+        assert_eq!(server.paths(&query, 1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn counts_past_u64_and_u128() {
+        let server = diamonds(130);
+
+        assert_eq!(
+            server.count(&PathQuery::new("n0", "n70")),
+            Ok(BigUint::from(1u8) << 70)
+        );
+        assert_eq!(
+            server.count(&PathQuery::new("n0", "n130")),
+            Ok(BigUint::from(1u8) << 130)
+        );
+    }
+
+    #[test]
+    fn counts_long_chains_without_recursing() {
+        let input: String = (0..100_000)
+            .map(|i| format!("n{i}: n{}\n", i + 1))
+            .collect();
+        let server = Server::new(&input).unwrap();
+
+        assert_eq!(
+            server.count(&PathQuery::new("n0", "n100000")),
+            Ok(1u8.into())
+        );
+        assert_eq!(
+            server.paths(&PathQuery::new("n0", "n100000"), 1).unwrap()[0].len(),
+            100_001
+        );
+    }
+
+    #[test]
+    fn treats_unknown_devices_as_unreachable() {
+        let server = example();
+
+        assert_eq!(server.count(&PathQuery::new("nope", "out")), Ok(0u8.into()));
+        assert_eq!(
+            server.count(&PathQuery::new("svr", "out").through(["nope"])),
+            Ok(0u8.into())
+        );
+        assert_eq!(
+            server.count(&PathQuery::new("svr", "out").avoiding(["nope"])),
+            Ok(8u8.into())
+        );
+    }
+
+    #[test]
+    fn ignores_loops_off_the_paths() {
+        // One loop is unconnected, one only leaves from the end, and one
+        // goes through a forbidden device.
+        let server = Server::new("a: b\nb: c d\nc: b\nd: a e\nx: y\ny: x\n").unwrap();
+        let query = PathQuery::new("a", "d").avoiding(["c"]);
+
+        assert_eq!(server.count(&query), Ok(1u8.into()));
+        assert_eq!(server.check(&query), Ok(()));
+        assert_eq!(
+            server.paths(&query, usize::MAX),
+            Ok(vec![vec!["a", "b", "d"]])
+        );
+        assert!(server.count(&PathQuery::new("a", "d")).is_err());
+        assert!(server.check(&PathQuery::new("a", "e")).is_err());
+    }
+
+    #[test]
+    fn never_revisits_a_device_within_a_length_limit() {
+        // Two ways from a to d, each of which can detour round the loop
+        // between b and c, but only once without revisiting b or c.
+        let server = Server::new("a: b c\nb: c d\nc: b d\n").unwrap();
+        let query = PathQuery::new("a", "d").max_length(10);
+
+        assert_eq!(server.count(&query), Ok(4u8.into()));
+        assert_eq!(
+            server.paths(&query, usize::MAX).unwrap(),
+            [
+                ["a", "b", "c", "d"].as_slice(),
+                &["a", "b", "d"],
+                &["a", "c", "b", "d"],
+                &["a", "c", "d"],
+            ]
+        );
+        assert_eq!(
+            server.count(&PathQuery::new("a", "d").max_length(10).through(["c"])),
+            Ok(3u8.into())
+        );
+        assert_eq!(
+            server.count(&PathQuery::new("a", "d").max_length(2)),
+            Ok(2u8.into())
+        );
+    }
+
+    #[test]
+    fn clamps_huge_length_limits() {
+        let server = Server::new("a: b\nb: a c\n").unwrap();
+
+        assert_eq!(
+            server.count(&PathQuery::new("a", "c").max_length(usize::MAX)),
+            Ok(1u8.into())
+        );
+        assert_eq!(
+            server.paths(&PathQuery::new("a", "c").max_length(10_000_000), usize::MAX),
+            Ok(vec![vec!["a", "b", "c"]])
+        );
+        assert_eq!(
+            diamonds(3).count(&PathQuery::new("n0", "n3").max_length(usize::MAX)),
+            Ok(8u8.into())
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        let server = Server::new("a: b\nb: a c\n").unwrap();
+        assert_eq!(
+            server.count(&PathQuery::new("a", "c")),
+            Err(QueryError::Cycle(Cycle {
+                devices: vec!["a".to_string(), "b".to_string(), "a".to_string()]
+            }))
+        );
+        // Only a -> b -> c: going round to a again would revisit it.
+        assert_eq!(
+            server.count(&PathQuery::new("a", "c").max_length(5)),
+            Ok(1u8.into())
+        );
+
+        let names: Vec<String> = (0..65).map(|i| format!("n{i}")).collect();
+        let query = PathQuery::new("n0", "n1").through(names.iter().map(String::as_str));
+        assert_eq!(server.count(&query), Err(QueryError::TooManyRequired(65)));
+    }
+}
@@ -0,0 +1,35 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use day_12::ChristmasTree;
+
+const SHAPES: &str = "0:\n###\n##.\n##.\n\n1:\n###\n##.\n.##\n\n2:\n.##\n###\n##.\n\n3:\n##.\n###\n##.\n\n4:\n###\n#..\n###\n\n5:\n###\n.#.\n###";
+
+/// A square region of side `size` asked to hold `presents` presents, cycling
+/// through the six example shapes so roughly half the area is covered.
+fn tree(size: usize, presents: usize) -> ChristmasTree {
+    let mut counts = [0; 6];
+    for i in 0..presents {
+        counts[i % 6] += 1;
+    }
+    let counts: Vec<String> = counts.iter().map(u64::to_string).collect();
+    let input = format!("{SHAPES}\n\n{size}x{size}: {}", counts.join(" "));
+
+    ChristmasTree::new(&input).unwrap()
+}
+
+fn solve(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ChristmasTree::can_fit_region");
+
+    for (size, presents) in [(6, 2), (9, 5), (12, 10)] {
+        let tree = tree(size, presents);
+        let region = &tree.regions()[0];
+
+        group.bench_function(BenchmarkId::from_parameter(format!("{size}x{size}")), |b| {
+            b.iter(|| tree.can_fit_region(region))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, solve);
+criterion_main!(benches);
@@ -0,0 +1,213 @@
+//! Quick tests that can settle whether a region fits without searching for
+//! a packing, run in order until one gives an answer.
+
+use crate::{ChristmasTree, Region};
+use aoc_core::{Deadline, TimedOut};
+
+/// A test that may decide a region: `Some(true)` if the presents surely
+/// fit, `Some(false)` if they surely do not, `None` if it cannot tell.
+#[derive(Clone, Copy)]
+pub struct PreCheck {
+    pub name: &'static str,
+    pub check: fn(&ChristmasTree, &Region) -> Option<bool>,
+}
+
+/// The checks [`ChristmasTree::decide`] runs by default, cheapest first.
+pub const PRE_CHECKS: &[PreCheck] = &[
+    PreCheck {
+        name: "area bound",
+        check: area_bound,
+    },
+    PreCheck {
+        name: "block bound",
+        check: block_bound,
+    },
+    PreCheck {
+        name: "parity",
+        check: parity,
+    },
+];
+
+/// How a region was decided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verdict {
+    pub fits: bool,
+    /// The name of the check that decided, or `"search"`.
+    pub decided_by: &'static str,
+}
+
+impl ChristmasTree {
+    /// Whether the region's presents fit, trying each of `checks` before
+    /// falling back to searching for a packing.
+    pub fn decide(&self, region: &Region, checks: &[PreCheck]) -> Verdict {
+        self.decide_until(region, checks, Deadline::NEVER)
+            .expect("a search without a deadline cannot time out")
+    }
+
+    /// Like [`decide`](Self::decide), but gives up on the search once
+    /// `deadline` passes.
+    pub fn decide_until(
+        &self,
+        region: &Region,
+        checks: &[PreCheck],
+        deadline: Deadline,
+    ) -> Result<Verdict, TimedOut> {
+        let decided = checks.iter().find_map(|check| {
+            (check.check)(self, region).map(|fits| Verdict {
+                fits,
+                decided_by: check.name,
+            })
+        });
+        match decided {
+            Some(verdict) => Ok(verdict),
+            None => Ok(Verdict {
+                fits: self.pack_region_until(region, deadline)?.is_some(),
+                decided_by: "search",
+            }),
+        }
+    }
+
+    pub(crate) fn needed_cells(&self, region: &Region) -> usize {
+        region
+            .presents
+            .iter()
+            .map(|p| self.shapes[p.shape_index].cell_count() * p.quantity as usize)
+            .sum()
+    }
+}
+
+/// The presents need more cells than the region has.
+pub fn area_bound(tree: &ChristmasTree, region: &Region) -> Option<bool> {
+    (tree.needed_cells(region) > region.width * region.height).then_some(false)
+}
+
+/// Every present fits in a square block of the largest shape's side, so if
+/// the region holds a block per present they fit side by side.
+pub fn block_bound(tree: &ChristmasTree, region: &Region) -> Option<bool> {
+    let side = region
+        .presents
+        .iter()
+        .map(|p| {
+            let (height, width) = tree.shapes[p.shape_index].bounding_box();
+            height.max(width) as usize
+        })
+        .max()
+        .filter(|&side| side > 0)?;
+    let presents: u64 = region.presents.iter().map(|p| p.quantity).sum();
+    let blocks = (region.width / side * (region.height / side)) as u64;
+
+    (blocks >= presents).then_some(true)
+}
+
+/// Colouring the region like a chessboard, each placed shape covers one of
+/// a few counts of black cells. If no mix of those counts leaves both the
+/// black and the white cells needed within what the region has, nothing fits.
+pub fn parity(tree: &ChristmasTree, region: &Region) -> Option<bool> {
+    let needed = tree.needed_cells(region);
+    let black = (region.width * region.height).div_ceil(2);
+    let white = region.width * region.height / 2;
+
+    // Which totals of black cells the presents placed so far can cover.
+    let mut reachable = vec![false; needed + 1];
+    reachable[0] = true;
+    for present in &region.presents {
+        let counts = black_counts(tree, present.shape_index);
+        for _ in 0..present.quantity {
+            let mut next = vec![false; needed + 1];
+            for total in (0..=needed).filter(|&t| reachable[t]) {
+                for &count in &counts {
+                    if total + count <= needed {
+                        next[total + count] = true;
+                    }
+                }
+            }
+            reachable = next;
+        }
+    }
+
+    let fits = (0..=needed).any(|t| reachable[t] && t <= black && needed - t <= white);
+    (!fits).then_some(false)
+}
+
+/// The black cells a shape can cover: moving it one cell swaps its colours,
+/// so each orientation covers either its own count or the rest.
+fn black_counts(tree: &ChristmasTree, shape: usize) -> Vec<usize> {
+    let mut counts: Vec<usize> = tree.shape_orientations[shape]
+        .iter()
+        .flat_map(|orientation| {
+            let black = orientation
+                .cells()
+                .iter()
+                .filter(|&&(r, c)| (r + c) % 2 == 0)
+                .count();
+            [black, orientation.cell_count() - black]
+        })
+        .collect();
+    counts.sort_unstable();
+    counts.dedup();
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decide(input: &str) -> Verdict {
+        let tree = ChristmasTree::new(input).unwrap();
+        tree.decide(&tree.regions()[0], PRE_CHECKS)
+    }
+
+    #[test]
+    fn reports_the_rule_that_decided() {
+        let verdict = |fits, decided_by| Verdict { fits, decided_by };
+        let shapes = "0:\n###\n#..\n###\n\n";
+
+        assert_eq!(
+            decide(&format!("{shapes}4x4: 3")),
+            verdict(false, "area bound")
+        );
+        assert_eq!(
+            decide(&format!("{shapes}6x7: 4")),
+            verdict(true, "block bound")
+        );
+        assert_eq!(decide(&format!("{shapes}4x4: 2")), verdict(true, "search"));
+        assert_eq!(decide(&format!("{shapes}3x5: 2")), verdict(false, "search"));
+    }
+
+    #[test]
+    fn rules_out_packings_by_parity() {
+        // Each T covers one or three black cells, so three of them cover an
+        // odd number and cannot fill a 4x3 board's six black cells.
+        assert_eq!(
+            decide("0:\n###\n.#.\n\n4x3: 3"),
+            Verdict {
+                fits: false,
+                decided_by: "parity"
+            }
+        );
+        assert!(decide("0:\n###\n.#.\n\n4x4: 4").fits);
+    }
+
+    #[test]
+    fn decides_the_example_regions() {
+        let tree = ChristmasTree::new(include_str!("../test.txt")).unwrap();
+        let verdicts: Vec<Verdict> = tree
+            .regions()
+            .iter()
+            .map(|region| tree.decide(region, PRE_CHECKS))
+            .collect();
+
+        assert_eq!(
+            verdicts.iter().map(|v| v.fits).collect::<Vec<_>>(),
+            [true, true, false]
+        );
+        assert!(verdicts.iter().all(|v| v.decided_by == "search"));
+        assert_eq!(
+            tree.decide(&tree.regions()[0], &[]),
+            Verdict {
+                fits: true,
+                decided_by: "search"
+            }
+        );
+    }
+}
@@ -0,0 +1,498 @@
+//! Knuth's Algorithm X over dancing links, for exact cover problems.
+//!
+//! Primary columns must be covered by exactly as many chosen rows as their
+//! multiplicity (once unless set otherwise), and secondary columns by at most
+//! one. Packing presents makes each present a primary column and each cell a
+//! secondary one, since cells may stay empty.
+//!
+//! A column with multiplicity takes its rows in order, each after the one
+//! chosen before it, so the search never tries the same set of rows twice.
+//! Its first row can also be limited to the ones added earliest, which
+//! breaks symmetries by saying one copy must come from those.
+
+use aoc_core::{Deadline, TimedOut};
+
+/// One entry of the matrix, linked to its neighbours in its row and column.
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    left: u32,
+    right: u32,
+    up: u32,
+    down: u32,
+    /// The header of the node's column.
+    column: u32,
+    /// The row the node belongs to; unused for headers.
+    row: u32,
+}
+
+/// The matrix as nodes linked by index: node 0 is the root, nodes
+/// `1..=columns` the column headers and the rest the entries of each row.
+pub struct ExactCover {
+    primary: usize,
+    nodes: Vec<Node>,
+    /// For each header, the rows left in its column.
+    len: Vec<usize>,
+    /// For each header, the rows it still needs, or 0 once covered.
+    need: Vec<usize>,
+    /// For each header, the rows it needs in all.
+    times: Vec<usize>,
+    /// For each header, the row its first chosen row must come before.
+    first_before: Vec<usize>,
+    rows: usize,
+}
+
+const ROOT: usize = 0;
+
+/// How many rows to try between looks at the clock.
+const ROWS_PER_CHECK: usize = 4096;
+
+/// The rows chosen so far, and when to give up.
+struct Search {
+    chosen: Vec<usize>,
+    deadline: Deadline,
+    tried: usize,
+}
+
+impl Search {
+    fn try_row(&mut self, row: usize) -> Result<(), TimedOut> {
+        self.tried += 1;
+        if self.tried.is_multiple_of(ROWS_PER_CHECK) {
+            self.deadline.check()?;
+        }
+        self.chosen.push(row);
+        Ok(())
+    }
+}
+
+impl ExactCover {
+    /// A matrix with columns `0..primary` primary and the next `secondary`
+    /// columns secondary, and no rows yet.
+    pub fn new(primary: usize, secondary: usize) -> Self {
+        let headers = primary + secondary + 1;
+        let mut nodes: Vec<Node> = (0..headers as u32)
+            .map(|header| Node {
+                left: header,
+                right: header,
+                up: header,
+                down: header,
+                column: header,
+                row: u32::MAX,
+            })
+            .collect();
+
+        // Only the primary headers join the root's list; the search is done
+        // when that list is empty.
+        for header in 0..=primary {
+            let next = (header + 1) % (primary + 1);
+            nodes[header].right = next as u32;
+            nodes[next].left = header as u32;
+        }
+
+        Self {
+            primary,
+            nodes,
+            len: vec![0; headers],
+            need: vec![1; headers],
+            times: vec![1; headers],
+            first_before: vec![usize::MAX; headers],
+            rows: 0,
+        }
+    }
+
+    /// Requires the primary `column` to be covered by `times` rows.
+    pub fn set_multiplicity(&mut self, column: usize, times: usize) {
+        assert!(
+            column < self.primary,
+            "only primary columns have a multiplicity"
+        );
+        assert!(times > 0, "a column must be covered at least once");
+        self.need[column + 1] = times;
+        self.times[column + 1] = times;
+    }
+
+    /// Requires the first row chosen for the primary `column`, which is the
+    /// earliest of its rows as they are taken in order, to have been added
+    /// before row `before`.
+    pub fn limit_first_row(&mut self, column: usize, before: usize) {
+        assert!(
+            column < self.primary,
+            "only primary columns take rows in order"
+        );
+        self.first_before[column + 1] = before;
+    }
+
+    /// Adds a row covering `columns`, returning its index.
+    pub fn add_row(&mut self, columns: impl IntoIterator<Item = usize>) -> usize {
+        let row = self.rows;
+        let first = self.nodes.len();
+
+        for column in columns {
+            let header = column + 1;
+            assert!(header < self.len.len(), "no column {column}");
+            let node = self.nodes.len();
+            let above = self.nodes[header].up as usize;
+
+            self.nodes.push(Node {
+                left: (node - 1).max(first) as u32,
+                right: first as u32,
+                up: above as u32,
+                down: header as u32,
+                column: header as u32,
+                row: row as u32,
+            });
+            self.nodes[above].down = node as u32;
+            self.nodes[header].up = node as u32;
+            self.len[header] += 1;
+
+            if node > first {
+                self.nodes[node - 1].right = node as u32;
+            }
+            self.nodes[first].left = node as u32;
+        }
+
+        assert!(self.nodes.len() > first, "a row must cover some column");
+        self.rows += 1;
+        row
+    }
+
+    /// The rows of some exact cover, or `None` if there is none.
+    pub fn solve(self) -> Option<Vec<usize>> {
+        self.solve_until(Deadline::NEVER)
+            .expect("a search without a deadline cannot time out")
+    }
+
+    /// Like [`solve`](Self::solve), but gives up once `deadline` passes.
+    pub fn solve_until(mut self, deadline: Deadline) -> Result<Option<Vec<usize>>, TimedOut> {
+        let mut search = Search {
+            chosen: Vec::new(),
+            deadline,
+            tried: 0,
+        };
+        Ok(self.search(&mut search)?.then_some(search.chosen))
+    }
+
+    /// Like [`solve`](Self::solve), also returning how many rows the search
+    /// tried, to compare how well matrices for the same problem prune it.
+    pub fn solve_counting(mut self) -> (Option<Vec<usize>>, usize) {
+        let mut search = Search {
+            chosen: Vec::new(),
+            deadline: Deadline::NEVER,
+            tried: 0,
+        };
+        let solved = self
+            .search(&mut search)
+            .expect("a search without a deadline cannot time out");
+        (solved.then_some(search.chosen), search.tried)
+    }
+
+    fn search(&mut self, search: &mut Search) -> Result<bool, TimedOut> {
+        let Some(column) = self.choose_column() else {
+            return Ok(true);
+        };
+        if self.len[column] < self.need[column] {
+            return Ok(false);
+        }
+        // Later rows follow the first in order, so only it needs limiting.
+        let before = if self.need[column] == self.times[column] {
+            self.first_before[column]
+        } else {
+            usize::MAX
+        };
+
+        if self.need[column] == 1 {
+            self.cover(column);
+            let mut node = self.down(column);
+            while node != column && (self.nodes[node].row as usize) < before {
+                search.try_row(self.nodes[node].row as usize)?;
+                self.take_others(node);
+                if self.search(search)? {
+                    return Ok(true);
+                }
+                self.release_others(node);
+                search.chosen.pop();
+                node = self.down(node);
+            }
+            self.uncover(column);
+        } else {
+            // Each row tried is hidden for the rest of the loop, so the next
+            // copy can only come from the rows after it.
+            let mut hidden = Vec::new();
+            let mut node = self.down(column);
+            while node != column
+                && self.len[column] >= self.need[column]
+                && (self.nodes[node].row as usize) < before
+            {
+                self.hide_row(node);
+                hidden.push(node);
+                self.need[column] -= 1;
+                search.try_row(self.nodes[node].row as usize)?;
+                self.take_others(node);
+                if self.search(search)? {
+                    return Ok(true);
+                }
+                self.release_others(node);
+                search.chosen.pop();
+                self.need[column] += 1;
+                node = self.down(node);
+            }
+            for &node in hidden.iter().rev() {
+                self.unhide_row(node);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// The primary column with the fewest spare rows, if any is uncovered.
+    fn choose_column(&self) -> Option<usize> {
+        let mut best = None;
+        let mut fewest = usize::MAX;
+        let mut header = self.right(ROOT);
+        while header != ROOT {
+            let spare = self.len[header].saturating_sub(self.need[header] - 1);
+            if spare < fewest {
+                best = Some(header);
+                fewest = spare;
+            }
+            header = self.right(header);
+        }
+        best
+    }
+
+    /// Takes up the columns of `node`'s row other than its own.
+    fn take_others(&mut self, node: usize) {
+        let mut other = self.right(node);
+        while other != node {
+            let header = self.column(other);
+            if self.need[header] > 1 {
+                self.need[header] -= 1;
+            } else {
+                self.cover(header);
+                self.need[header] = 0;
+            }
+            other = self.right(other);
+        }
+    }
+
+    fn release_others(&mut self, node: usize) {
+        let mut other = self.left(node);
+        while other != node {
+            let header = self.column(other);
+            if self.need[header] == 0 {
+                self.need[header] = 1;
+                self.uncover(header);
+            } else {
+                self.need[header] += 1;
+            }
+            other = self.left(other);
+        }
+    }
+
+    /// Removes `header` from the header list and every row in its column
+    /// from the other columns.
+    fn cover(&mut self, header: usize) {
+        let (left, right) = (self.left(header), self.right(header));
+        self.nodes[left].right = right as u32;
+        self.nodes[right].left = left as u32;
+
+        let mut node = self.down(header);
+        while node != header {
+            let mut other = self.right(node);
+            while other != node {
+                self.unlink_vertically(other);
+                other = self.right(other);
+            }
+            node = self.down(node);
+        }
+    }
+
+    fn uncover(&mut self, header: usize) {
+        let mut node = self.up(header);
+        while node != header {
+            let mut other = self.left(node);
+            while other != node {
+                self.relink_vertically(other);
+                other = self.left(other);
+            }
+            node = self.up(node);
+        }
+
+        let (left, right) = (self.left(header), self.right(header));
+        self.nodes[left].right = header as u32;
+        self.nodes[right].left = header as u32;
+    }
+
+    /// Removes `node`'s whole row, including `node`, from its columns.
+    fn hide_row(&mut self, node: usize) {
+        let mut other = node;
+        loop {
+            self.unlink_vertically(other);
+            other = self.right(other);
+            if other == node {
+                break;
+            }
+        }
+    }
+
+    fn unhide_row(&mut self, node: usize) {
+        let mut other = self.left(node);
+        loop {
+            self.relink_vertically(other);
+            if other == node {
+                break;
+            }
+            other = self.left(other);
+        }
+    }
+
+    fn unlink_vertically(&mut self, node: usize) {
+        let Node {
+            up, down, column, ..
+        } = self.nodes[node];
+        self.nodes[up as usize].down = down;
+        self.nodes[down as usize].up = up;
+        self.len[column as usize] -= 1;
+    }
+
+    fn relink_vertically(&mut self, node: usize) {
+        let Node {
+            up, down, column, ..
+        } = self.nodes[node];
+        self.nodes[up as usize].down = node as u32;
+        self.nodes[down as usize].up = node as u32;
+        self.len[column as usize] += 1;
+    }
+
+    fn left(&self, node: usize) -> usize {
+        self.nodes[node].left as usize
+    }
+
+    fn right(&self, node: usize) -> usize {
+        self.nodes[node].right as usize
+    }
+
+    fn up(&self, node: usize) -> usize {
+        self.nodes[node].up as usize
+    }
+
+    fn down(&self, node: usize) -> usize {
+        self.nodes[node].down as usize
+    }
+
+    fn column(&self, node: usize) -> usize {
+        self.nodes[node].column as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn solve(matrix: ExactCover) -> Option<Vec<usize>> {
+        matrix.solve().map(|mut rows| {
+            rows.sort();
+            rows
+        })
+    }
+
+    #[test]
+    fn solves_knuths_example() {
+        // Columns A to G, with the rows from "Dancing Links".
+        let mut matrix = ExactCover::new(7, 0);
+        for row in [
+            vec![2, 4, 5],
+            vec![0, 3, 6],
+            vec![1, 2, 5],
+            vec![0, 3],
+            vec![1, 6],
+            vec![3, 4, 6],
+        ] {
+            matrix.add_row(row);
+        }
+
+        assert_eq!(solve(matrix), Some(vec![0, 3, 4]));
+    }
+
+    #[test]
+    fn leaves_secondary_columns_uncovered_but_never_shared() {
+        // One primary column and two secondary ones, covered by either row
+        // alone but never by both.
+        let mut matrix = ExactCover::new(2, 2);
+        matrix.add_row([0, 2, 3]);
+        matrix.add_row([1, 3]);
+        matrix.add_row([1]);
+        assert_eq!(solve(matrix), Some(vec![0, 2]));
+
+        let mut matrix = ExactCover::new(2, 1);
+        matrix.add_row([0, 2]);
+        matrix.add_row([1, 2]);
+        assert_eq!(solve(matrix), None);
+    }
+
+    #[test]
+    fn covers_columns_with_multiplicity() {
+        // Three dominoes tiling a 2x3 board, as three copies of one piece.
+        let cell = |r: usize, c: usize| 1 + r * 3 + c;
+        let mut matrix = ExactCover::new(1, 6);
+        matrix.set_multiplicity(0, 3);
+        for r in 0..2 {
+            for c in 0..3 {
+                if c + 1 < 3 {
+                    matrix.add_row([0, cell(r, c), cell(r, c + 1)]);
+                }
+                if r + 1 < 2 {
+                    matrix.add_row([0, cell(r, c), cell(r + 1, c)]);
+                }
+            }
+        }
+        assert_eq!(solve(matrix).map(|rows| rows.len()), Some(3));
+
+        // Four copies cannot fit in six cells.
+        let mut matrix = ExactCover::new(1, 6);
+        matrix.set_multiplicity(0, 4);
+        for c in 0..5 {
+            matrix.add_row([0, 1 + c, 2 + c]);
+        }
+        assert_eq!(solve(matrix), None);
+    }
+
+    #[test]
+    fn limits_the_first_row_of_a_column() {
+        // Two dominoes tiling a 2x2 board, both horizontal (rows 0 and 1)
+        // or both vertical (rows 2 and 3).
+        let dominoes = |before: usize| {
+            let mut matrix = ExactCover::new(1, 4);
+            matrix.set_multiplicity(0, 2);
+            for cells in [[1, 2], [3, 4], [1, 3], [2, 4]] {
+                matrix.add_row([0, cells[0], cells[1]]);
+            }
+            matrix.limit_first_row(0, before);
+            matrix
+        };
+
+        assert_eq!(solve(dominoes(usize::MAX)), Some(vec![0, 1]));
+        assert_eq!(solve(dominoes(1)), Some(vec![0, 1]));
+        assert_eq!(solve(dominoes(0)), None);
+
+        // Once the first domino is placed, the second may be any later row.
+        let (solution, tried) = dominoes(1).solve_counting();
+        assert_eq!((solution, tried), (Some(vec![0, 1]), 2));
+    }
+
+    #[test]
+    fn gives_up_at_the_deadline() {
+        // Nine pigeons cannot share eight holes, but proving it takes
+        // far longer than no time at all.
+        let mut matrix = ExactCover::new(9, 8);
+        for pigeon in 0..9 {
+            for hole in 9..17 {
+                matrix.add_row([pigeon, hole]);
+            }
+        }
+        assert_eq!(
+            matrix.solve_until(Deadline::after(Duration::ZERO)),
+            Err(TimedOut)
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! Where each present in a packed region goes, drawn as text like the
+//! puzzle's illustrations or as SVG for regions too large to read as text.
+
+use crate::{ChristmasTree, Region};
+use aoc_core::Grid;
+use std::fmt::{self, Write};
+
+/// The side of a cell in the SVG, in pixels.
+const SVG_CELL: usize = 12;
+
+/// One present: the shape, which of its orientations, and the top-left
+/// corner of that orientation's bounding box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub shape: usize,
+    pub orientation: usize,
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub width: usize,
+    pub height: usize,
+    /// From top-left to bottom-right by corner.
+    pub placements: Vec<Placement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The layout is for a region of a different size.
+    WrongSize,
+    /// The placement names a shape or orientation that does not exist.
+    UnknownShape {
+        present: usize,
+    },
+    OutOfBounds {
+        present: usize,
+    },
+    Overlap {
+        row: usize,
+        column: usize,
+    },
+    WrongCount {
+        shape: usize,
+        wanted: u64,
+        placed: u64,
+    },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayoutError::WrongSize => write!(f, "the layout is not the size of the region"),
+            LayoutError::UnknownShape { present } => {
+                write!(f, "present {present} has no such shape or orientation")
+            }
+            LayoutError::OutOfBounds { present } => {
+                write!(f, "present {present} sticks out of the region")
+            }
+            LayoutError::Overlap { row, column } => {
+                write!(f, "presents overlap at row {row}, column {column}")
+            }
+            LayoutError::WrongCount {
+                shape,
+                wanted,
+                placed,
+            } => write!(
+                f,
+                "shape {shape} is wanted {wanted} times but placed {placed}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// The letter for the `present`th present: `A` to `Z`, then `a` to `z`,
+/// then round again.
+fn letter(present: usize) -> char {
+    const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    LETTERS[present % LETTERS.len()] as char
+}
+
+impl ChristmasTree {
+    /// The cells `placement` covers, as `(row, column)`, or `None` if it
+    /// names a shape or orientation that does not exist.
+    fn placed_cells(&self, placement: &Placement) -> Option<Vec<(usize, usize)>> {
+        let shape = self
+            .shape_orientations
+            .get(placement.shape)?
+            .get(placement.orientation)?;
+        Some(
+            shape
+                .cells()
+                .iter()
+                .map(|&(r, c)| (placement.row + r as usize, placement.column + c as usize))
+                .collect(),
+        )
+    }
+
+    /// Each cell's present, by its index in the layout.
+    fn paint(&self, layout: &Layout) -> Result<Grid<Option<usize>>, LayoutError> {
+        let mut grid = Grid::new(layout.width, layout.height, None);
+        for (present, placement) in layout.placements.iter().enumerate() {
+            let cells = self
+                .placed_cells(placement)
+                .ok_or(LayoutError::UnknownShape { present })?;
+            for (row, column) in cells {
+                match grid.get_mut(column, row) {
+                    None => return Err(LayoutError::OutOfBounds { present }),
+                    Some(Some(_)) => return Err(LayoutError::Overlap { row, column }),
+                    Some(cell) => *cell = Some(present),
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Checks `layout` packs exactly the presents `region` asks for, with
+    /// none overlapping or sticking out.
+    pub fn verify_layout(&self, region: &Region, layout: &Layout) -> Result<(), LayoutError> {
+        if (layout.width, layout.height) != (region.width, region.height) {
+            return Err(LayoutError::WrongSize);
+        }
+        self.paint(layout)?;
+
+        let mut placed = vec![0; self.shapes.len()];
+        for (present, placement) in layout.placements.iter().enumerate() {
+            *placed
+                .get_mut(placement.shape)
+                .ok_or(LayoutError::UnknownShape { present })? += 1;
+        }
+        for (shape, &placed) in placed.iter().enumerate() {
+            let wanted = region
+                .presents
+                .iter()
+                .filter(|p| p.shape_index == shape)
+                .map(|p| p.quantity)
+                .sum();
+            if placed != wanted {
+                return Err(LayoutError::WrongCount {
+                    shape,
+                    wanted,
+                    placed,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The layout as text, one letter per present and `.` for empty cells.
+    pub fn render_layout(&self, layout: &Layout) -> Result<String, LayoutError> {
+        let grid = self.paint(layout)?;
+        let mut text = String::with_capacity((layout.width + 1) * layout.height);
+        for row in grid.rows() {
+            text.extend(row.iter().map(|cell| cell.map_or('.', letter)));
+            text.push('\n');
+        }
+        Ok(text)
+    }
+
+    /// The layout as an SVG image, with each present in its own colour.
+    pub fn layout_svg(&self, layout: &Layout) -> Result<String, LayoutError> {
+        let grid = self.paint(layout)?;
+        let (width, height) = (layout.width * SVG_CELL, layout.height * SVG_CELL);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+        );
+        let _ = writeln!(
+            svg,
+            "  <rect width=\"{width}\" height=\"{height}\" fill=\"#f5f5f5\"/>"
+        );
+        for ((column, row), cell) in grid.iter() {
+            if let Some(present) = cell {
+                // Stepping the hue by the golden angle keeps neighbours apart.
+                let hue = present * 137 % 360;
+                let _ = writeln!(
+                    svg,
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{SVG_CELL}\" height=\"{SVG_CELL}\" fill=\"hsl({hue}, 65%, 55%)\"><title>{}</title></rect>",
+                    column * SVG_CELL,
+                    row * SVG_CELL,
+                    letter(*present)
+                );
+            }
+        }
+        svg.push_str("</svg>\n");
+
+        Ok(svg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> ChristmasTree {
+        ChristmasTree::new(include_str!("../test.txt")).unwrap()
+    }
+
+    #[test]
+    fn renders_each_present_with_its_own_letter() {
+        let tree = example();
+        let region = &tree.regions()[0];
+        let layout = tree.pack_region(region).unwrap();
+
+        assert_eq!(tree.verify_layout(region, &layout), Ok(()));
+        assert_eq!(
+            tree.render_layout(&layout).unwrap(),
+            "AAA.\nABBB\nAAAB\n.BBB\n"
+        );
+
+        let svg = tree.layout_svg(&layout).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"48\""));
+        assert_eq!(svg.matches("<title>").count(), 14);
+    }
+
+    #[test]
+    fn verifies_the_packed_example_regions() {
+        let tree = example();
+        for region in &tree.regions()[..2] {
+            let layout = tree.pack_region(region).unwrap();
+            assert_eq!(tree.verify_layout(region, &layout), Ok(()));
+        }
+        assert_eq!(tree.pack_region(&tree.regions()[2]), None);
+    }
+
+    #[test]
+    fn rejects_bad_layouts() {
+        let tree = example();
+        let region = &tree.regions()[0];
+        let layout = tree.pack_region(region).unwrap();
+
+        let mut overlapping = layout.clone();
+        overlapping.placements[1] = overlapping.placements[0].clone();
+        assert!(matches!(
+            tree.verify_layout(region, &overlapping),
+            Err(LayoutError::Overlap { .. })
+        ));
+
+        let mut outside = layout.clone();
+        outside.placements[1].column = 2;
+        assert_eq!(
+            tree.verify_layout(region, &outside),
+            Err(LayoutError::OutOfBounds { present: 1 })
+        );
+
+        let mut short = layout.clone();
+        short.placements.pop();
+        assert_eq!(
+            tree.verify_layout(region, &short),
+            Err(LayoutError::WrongCount {
+                shape: 4,
+                wanted: 2,
+                placed: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use aoc_core::{
+    Answer, Batch, BatchOptions, Deadline, ParseError, Part, Solution, TimedOut, parse_at,
+    split_once_at,
+};
+use dlx::ExactCover;
+
+pub mod checks;
+pub mod dlx;
+pub mod layout;
+pub mod polyomino;
+
+pub use checks::{PRE_CHECKS, PreCheck, Verdict};
+pub use layout::{Layout, LayoutError, Placement};
+pub use polyomino::{Equivalence, Polyomino};
+
+/// The largest region, in cells, that `explain` draws as text.
+const MAX_DRAWN_CELLS: usize = 400;
+
+#[derive(Debug, Clone)]
+struct Present {
+    shape_index: usize,
+    quantity: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Region {
+    width: usize,
+    height: usize,
+    presents: Vec<Present>,
+}
+
+impl Region {
+    /// A region wanting `quantities[i]` presents of shape `i`.
+    pub fn new(width: usize, height: usize, quantities: &[u64]) -> Self {
+        let presents = quantities
+            .iter()
+            .enumerate()
+            .filter(|&(_, &quantity)| quantity > 0)
+            .map(|(shape_index, &quantity)| Present {
+                shape_index,
+                quantity,
+            })
+            .collect();
+
+        Self {
+            width,
+            height,
+            presents,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ChristmasTree {
+    shapes: Vec<Polyomino>,
+    shape_orientations: Vec<Vec<Polyomino>>,
+    regions: Vec<Region>,
+}
+
+impl ChristmasTree {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let sections: Vec<&str> = input.split("\n\n").collect();
+
+        let mut shapes: Vec<Polyomino> = Vec::new();
+
+        let mut region_start_section = 0;
+
+        for (i, section) in sections.iter().enumerate() {
+            let first_line = section.lines().next().unwrap_or("");
+
+            if first_line.contains('x')
+                && first_line
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_digit())
+            {
+                region_start_section = i;
+                break;
+            }
+
+            let Some((_, shape_lines)) = section.split_once('\n') else {
+                continue;
+            };
+            let shape_lines = shape_lines.trim_end_matches('\n');
+
+            if !shape_lines.is_empty() {
+                let shape = shape_lines
+                    .parse()
+                    .map_err(|e: ParseError| e.relative_to(input, shape_lines))?;
+                shapes.push(shape);
+            }
+        }
+
+        let shape_orientations: Vec<Vec<Polyomino>> =
+            shapes.iter().map(|s| s.all_orientations()).collect();
+
+        let regions: Vec<Region> = sections[region_start_section..]
+            .iter()
+            .flat_map(|section| section.lines())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (dimensions, quantities_str) = split_once_at(input, line, ": ")?;
+                let (width_str, height_str) = split_once_at(input, dimensions, "x")?;
+
+                let width: usize = parse_at(input, width_str, "a width")?;
+                let height: usize = parse_at(input, height_str, "a height")?;
+
+                let mut presents: Vec<Present> = Vec::new();
+                for (shape_index, qty_str) in quantities_str.split_whitespace().enumerate() {
+                    let quantity: u64 = parse_at(input, qty_str, "a quantity")?;
+                    if quantity == 0 {
+                        continue;
+                    }
+                    if shape_index >= shapes.len() {
+                        return Err(ParseError::at(
+                            input,
+                            qty_str,
+                            format!("no shape {shape_index} is defined"),
+                        ));
+                    }
+                    presents.push(Present {
+                        shape_index,
+                        quantity,
+                    });
+                }
+
+                Ok(Region {
+                    width,
+                    height,
+                    presents,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            shapes,
+            shape_orientations,
+            regions,
+        })
+    }
+
+    /// A puzzle made up rather than read, such as packing the pentominoes
+    /// into a rectangle.
+    pub fn from_shapes(shapes: Vec<Polyomino>, regions: Vec<Region>) -> Self {
+        assert!(
+            regions
+                .iter()
+                .flat_map(|region| &region.presents)
+                .all(|present| present.shape_index < shapes.len()),
+            "every present must be one of the shapes"
+        );
+        let shape_orientations = shapes.iter().map(|s| s.all_orientations()).collect();
+
+        Self {
+            shapes,
+            shape_orientations,
+            regions,
+        }
+    }
+
+    pub fn shapes(&self) -> &[Polyomino] {
+        &self.shapes
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    /// Where to put the region's presents so none overlap, found as an
+    /// exact cover: each present must be placed as many times as it is
+    /// wanted, and each cell used at most once.
+    ///
+    /// The search never tries two orders of identical presents, as the
+    /// cover takes each shape's placements in order. Nor does it try the
+    /// mirror images of a packing: flipping the region either way gives
+    /// another packing, so the first copy of the first present can be kept
+    /// to the top-left quarter.
+    pub fn pack_region(&self, region: &Region) -> Option<Layout> {
+        self.pack_region_until(region, Deadline::NEVER)
+            .expect("a search without a deadline cannot time out")
+    }
+
+    /// Like [`pack_region`](Self::pack_region), but gives up once
+    /// `deadline` passes.
+    pub fn pack_region_until(
+        &self,
+        region: &Region,
+        deadline: Deadline,
+    ) -> Result<Option<Layout>, TimedOut> {
+        if checks::area_bound(self, region).is_some() {
+            return Ok(None);
+        }
+        let (matrix, candidates) = self.cover_matrix(region, true);
+
+        let Some(rows) = matrix.solve_until(deadline)? else {
+            return Ok(None);
+        };
+        let mut placements: Vec<Placement> = rows
+            .into_iter()
+            .map(|row| candidates[row].clone())
+            .collect();
+        placements.sort_by_key(|p| (p.row, p.column));
+
+        Ok(Some(Layout {
+            width: region.width,
+            height: region.height,
+            placements,
+        }))
+    }
+
+    /// The exact cover for packing `region`, and the placement each row
+    /// stands for.
+    ///
+    /// With `break_mirrors`, the first present's placements in the top-left
+    /// quarter come first and its first copy must be one of them. Some copy
+    /// of it is there in one of each packing's mirror images, and the copies
+    /// are taken in order, so that copy is the first.
+    fn cover_matrix(&self, region: &Region, break_mirrors: bool) -> (ExactCover, Vec<Placement>) {
+        let cell = |r: i32, c: i32| region.presents.len() + r as usize * region.width + c as usize;
+        let mut candidates = Vec::new();
+        let mut matrix = ExactCover::new(region.presents.len(), region.width * region.height);
+
+        for (column, present) in region.presents.iter().enumerate() {
+            matrix.set_multiplicity(column, present.quantity as usize);
+            let mut rows = Vec::new();
+            for (index, orientation) in self.shape_orientations[present.shape_index]
+                .iter()
+                .enumerate()
+            {
+                let (height, width) = orientation.bounding_box();
+                for r in 0..=region.height as i32 - height {
+                    for c in 0..=region.width as i32 - width {
+                        let centred_past_middle = 2 * r + height > region.height as i32
+                            || 2 * c + width > region.width as i32;
+                        let cells: Vec<usize> = orientation
+                            .cells()
+                            .iter()
+                            .map(|&(dr, dc)| cell(r + dr, c + dc))
+                            .collect();
+                        let placement = Placement {
+                            shape: present.shape_index,
+                            orientation: index,
+                            row: r as usize,
+                            column: c as usize,
+                        };
+                        rows.push((centred_past_middle, cells, placement));
+                    }
+                }
+            }
+
+            if break_mirrors && column == 0 {
+                rows.sort_by_key(|&(centred_past_middle, ..)| centred_past_middle);
+                let in_quarter = rows.iter().filter(|(past, ..)| !past).count();
+                // A lone copy never uses the rest, and leaving them out
+                // keeps its column short for the search to choose early.
+                if present.quantity == 1 {
+                    rows.truncate(in_quarter);
+                } else {
+                    matrix.limit_first_row(column, candidates.len() + in_quarter);
+                }
+            }
+            for (_, cells, placement) in rows {
+                matrix.add_row(std::iter::once(column).chain(cells));
+                candidates.push(placement);
+            }
+        }
+
+        (matrix, candidates)
+    }
+
+    pub fn can_fit_region(&self, region: &Region) -> bool {
+        self.decide(region, PRE_CHECKS).fits
+    }
+
+    /// Whether each region fits, in order, deciding them as `batch` says.
+    pub fn fitting_regions(&self, batch: &Batch) -> Vec<Result<bool, TimedOut>> {
+        batch.run(&self.regions, |region, deadline| {
+            Ok(self.decide_until(region, PRE_CHECKS, deadline)?.fits)
+        })
+    }
+
+    pub fn count_fitting_regions(&self) -> usize {
+        self.fitting_regions(&Batch::new("regions"))
+            .into_iter()
+            .filter(|fits| *fits == Ok(true))
+            .count()
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = ChristmasTree;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        ChristmasTree::new(input)
+    }
+
+    fn part1(tree: &Self::Input) -> Answer {
+        Self::part1_with(tree, &BatchOptions::default())
+    }
+
+    fn part2(_tree: &Self::Input) -> Answer {
+        Answer::Empty
+    }
+
+    fn part1_with(tree: &Self::Input, options: &BatchOptions) -> Answer {
+        let fits = tree.fitting_regions(&Batch::with_options("regions", *options));
+        let count = fits.iter().filter(|fits| **fits == Ok(true)).count();
+        match fits.iter().filter(|fits| fits.is_err()).count() {
+            0 => count.into(),
+            timed_out => Answer::Text(format!("at least {count}; {timed_out} regions timed out")),
+        }
+    }
+
+    fn explain(tree: &Self::Input, part: Part) -> Option<String> {
+        if matches!(part, Part::Part2) {
+            return None;
+        }
+
+        let mut lines = Vec::new();
+        for (i, region) in tree.regions().iter().enumerate() {
+            let verdict = tree.decide(region, PRE_CHECKS);
+            lines.push(format!(
+                "Region {} ({}x{}): {} (decided by {})",
+                i + 1,
+                region.width,
+                region.height,
+                if verdict.fits { "fits" } else { "does not fit" },
+                verdict.decided_by
+            ));
+
+            if verdict.fits && region.width * region.height <= MAX_DRAWN_CELLS {
+                let layout = tree.pack_region(region).expect("the region fits");
+                let drawing = tree
+                    .render_layout(&layout)
+                    .expect("packed layouts are valid");
+                lines.extend(drawing.lines().map(|line| format!("  {line}")));
+            }
+        }
+
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChristmasTree, Day12, Equivalence, Polyomino, Region};
+    use aoc_core::Part::Part1;
+    use aoc_core::Solution;
+    use aoc_core::answers::expected;
+
+    #[test]
+    fn returns_expected_value_test_data_for_part_1() {
+        let value = Day12::solve_file("./test.txt", Part1);
+        assert_eq!(value, expected(12, "test", Part1));
+    }
+
+    #[test]
+    fn explains_how_each_region_is_packed() {
+        let tree = Day12::parse(include_str!("../test.txt")).unwrap();
+        let explanation = Day12::explain(&tree, Part1).unwrap();
+
+        assert!(explanation.starts_with("Region 1 (4x4): fits (decided by search)\n  AAA.\n"));
+        assert!(explanation.ends_with("Region 3 (12x5): does not fit (decided by search)"));
+    }
+
+    #[test]
+    fn packs_the_pentominoes_into_a_rectangle() {
+        let pentominoes = Polyomino::enumerate(5, Equivalence::Free);
+        let tree = ChristmasTree::from_shapes(pentominoes, vec![Region::new(10, 7, &[1; 12])]);
+        let region = &tree.regions()[0];
+
+        let layout = tree.pack_region(region).unwrap();
+        assert_eq!(tree.verify_layout(region, &layout), Ok(()));
+        assert_eq!(
+            tree.render_layout(&layout).unwrap().matches('.').count(),
+            10
+        );
+    }
+
+    #[test]
+    fn breaks_mirror_symmetry_when_every_present_repeats() {
+        // Three of one present and two of another cannot fill 6x6, and
+        // keeping the first copy to one quarter proves it in fewer nodes.
+        let tree = Day12::parse(include_str!("../test.txt")).unwrap();
+        let region = Region::new(6, 6, &[0, 0, 0, 0, 3, 2]);
+
+        let (anchored, with_anchor) = tree.cover_matrix(&region, true).0.solve_counting();
+        let (unanchored, without_anchor) = tree.cover_matrix(&region, false).0.solve_counting();
+        assert_eq!((anchored, unanchored), (None, None));
+        assert!(
+            with_anchor < without_anchor,
+            "{with_anchor} nodes with the anchor, {without_anchor} without"
+        );
+    }
+
+    #[test]
+    fn returns_expected_value_for_input_data_for_part_1() {
+        let value = Day12::solve_file("./input.txt", Part1);
+        assert_eq!(value, expected(12, "input", Part1));
+    }
+}
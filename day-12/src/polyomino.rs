@@ -0,0 +1,280 @@
+//! Shapes made of unit squares joined edge to edge, such as the presents.
+//!
+//! Two polyominoes can be the same shape in three senses: *fixed* if one is
+//! the other moved, *one-sided* if also turned, and *free* if also flipped
+//! over. Each sense picks its own canonical form, the smallest of the shapes
+//! it counts as the same, so comparing canonical forms compares shapes.
+
+use aoc_core::{Grid, ParseError};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+/// Which moves leave a polyomino the same shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Equivalence {
+    /// Moving it about.
+    Fixed,
+    /// Moving and turning it.
+    OneSided,
+    /// Moving, turning and flipping it.
+    Free,
+}
+
+/// The cells as `(row, column)`, moved so the topmost row and leftmost
+/// column are 0, in reading order. Ordering polyominoes compares these
+/// cell lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Polyomino {
+    cells: Vec<(i32, i32)>,
+}
+
+impl Polyomino {
+    pub fn new(cells: impl IntoIterator<Item = (i32, i32)>) -> Self {
+        let mut cells: Vec<(i32, i32)> = cells.into_iter().collect();
+        let min_r = cells.iter().map(|&(r, _)| r).min().unwrap_or(0);
+        let min_c = cells.iter().map(|&(_, c)| c).min().unwrap_or(0);
+        for (r, c) in &mut cells {
+            *r -= min_r;
+            *c -= min_c;
+        }
+        cells.sort_unstable();
+        cells.dedup();
+        Self { cells }
+    }
+
+    /// The `true` cells of `grid`.
+    pub fn from_grid(grid: &Grid<bool>) -> Self {
+        Self::new(
+            grid.iter()
+                .filter(|&(_, &cell)| cell)
+                .map(|((c, r), _)| (r as i32, c as i32)),
+        )
+    }
+
+    /// Every polyomino of `size` cells, one per shape under `equivalence`,
+    /// each in its canonical form and in order.
+    pub fn enumerate(size: usize, equivalence: Equivalence) -> Vec<Polyomino> {
+        Self::enumerate_up_to(size, equivalence)
+            .pop()
+            .unwrap_or_default()
+    }
+
+    /// The polyominoes of each size from 1 to `max_size`, as
+    /// [`enumerate`](Self::enumerate) gives them.
+    pub fn enumerate_up_to(max_size: usize, equivalence: Equivalence) -> Vec<Vec<Polyomino>> {
+        let mut sizes: Vec<Vec<Polyomino>> = Vec::with_capacity(max_size);
+        if max_size == 0 {
+            return sizes;
+        }
+
+        sizes.push(vec![Polyomino::new([(0, 0)])]);
+        // Each shape one cell larger is some smaller shape with a cell added,
+        // and growing any orientation of that smaller shape reaches some
+        // orientation of the larger one.
+        while sizes.len() < max_size {
+            let grown: BTreeSet<Polyomino> = sizes[sizes.len() - 1]
+                .iter()
+                .flat_map(Polyomino::grown)
+                .map(|polyomino| polyomino.canonical(equivalence))
+                .collect();
+            sizes.push(grown.into_iter().collect());
+        }
+        sizes
+    }
+
+    pub fn cells(&self) -> &[(i32, i32)] {
+        &self.cells
+    }
+
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The rows and columns the shape spans.
+    pub fn bounding_box(&self) -> (i32, i32) {
+        let height = self.cells.iter().map(|&(r, _)| r + 1).max().unwrap_or(0);
+        let width = self.cells.iter().map(|&(_, c)| c + 1).max().unwrap_or(0);
+        (height, width)
+    }
+
+    /// The length of the outline, holes included: each cell has four
+    /// edges, less the ones it shares with a neighbour.
+    pub fn perimeter(&self) -> usize {
+        let shared = self
+            .cells
+            .iter()
+            .flat_map(|&(r, c)| [(r, c + 1), (r + 1, c)])
+            .filter(|neighbour| self.contains(*neighbour))
+            .count();
+        4 * self.cells.len() - 2 * shared
+    }
+
+    fn contains(&self, cell: (i32, i32)) -> bool {
+        self.cells.binary_search(&cell).is_ok()
+    }
+
+    /// Turned a quarter turn clockwise.
+    pub fn rotate_90(&self) -> Self {
+        Self::new(self.cells.iter().map(|&(r, c)| (c, -r)))
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::new(self.cells.iter().map(|&(r, c)| (r, -c)))
+    }
+
+    /// The distinct ways the shape can be turned.
+    pub fn rotations(&self) -> Vec<Polyomino> {
+        let mut rotations = vec![self.clone()];
+        loop {
+            let next = rotations[rotations.len() - 1].rotate_90();
+            if next == *self {
+                return rotations;
+            }
+            rotations.push(next);
+        }
+    }
+
+    /// The distinct ways the shape can be turned or flipped.
+    pub fn all_orientations(&self) -> Vec<Polyomino> {
+        let mut seen: HashSet<Polyomino> = HashSet::new();
+        let mut orientations: Vec<Polyomino> = Vec::new();
+
+        let mut current = self.clone();
+        for _ in 0..4 {
+            if seen.insert(current.clone()) {
+                orientations.push(current.clone());
+            }
+            let flipped = current.flip_horizontal();
+            if seen.insert(flipped.clone()) {
+                orientations.push(flipped);
+            }
+            current = current.rotate_90();
+        }
+
+        orientations
+    }
+
+    /// The smallest of the shapes `equivalence` counts as this one.
+    pub fn canonical(&self, equivalence: Equivalence) -> Polyomino {
+        match equivalence {
+            Equivalence::Fixed => self.clone(),
+            Equivalence::OneSided => self.rotations().into_iter().min().unwrap_or_default(),
+            Equivalence::Free => self
+                .all_orientations()
+                .into_iter()
+                .min()
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn is_equivalent(&self, other: &Polyomino, equivalence: Equivalence) -> bool {
+        self.canonical(equivalence) == other.canonical(equivalence)
+    }
+
+    /// The strictest sense in which the two are the same shape, or `None`
+    /// if they are different shapes even when flipped.
+    pub fn classify(&self, other: &Polyomino) -> Option<Equivalence> {
+        [Equivalence::Fixed, Equivalence::OneSided, Equivalence::Free]
+            .into_iter()
+            .find(|&equivalence| self.is_equivalent(other, equivalence))
+    }
+
+    /// The shape with one more cell for each free cell beside it.
+    fn grown(&self) -> Vec<Polyomino> {
+        let neighbours: BTreeSet<(i32, i32)> = self
+            .cells
+            .iter()
+            .flat_map(|&(r, c)| [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)])
+            .filter(|&cell| !self.contains(cell))
+            .collect();
+        neighbours
+            .into_iter()
+            .map(|cell| Polyomino::new(self.cells.iter().copied().chain([cell])))
+            .collect()
+    }
+}
+
+/// Parses rows of `#` for cells and `.` for gaps, as the puzzle draws
+/// presents.
+impl FromStr for Polyomino {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(Self::from_grid(&grid))
+    }
+}
+
+/// Draws the shape as the puzzle does, one line per row.
+impl fmt::Display for Polyomino {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (height, width) = self.bounding_box();
+        for r in 0..height {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for c in 0..width {
+                write!(f, "{}", if self.contains((r, c)) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(equivalence: Equivalence) -> Vec<usize> {
+        Polyomino::enumerate_up_to(7, equivalence)
+            .iter()
+            .map(Vec::len)
+            .collect()
+    }
+
+    #[test]
+    fn counts_the_polyominoes_of_each_size() {
+        assert_eq!(counts(Equivalence::Free), [1, 1, 2, 5, 12, 35, 108]);
+        assert_eq!(counts(Equivalence::OneSided), [1, 1, 2, 7, 18, 60, 196]);
+        assert_eq!(counts(Equivalence::Fixed), [1, 2, 6, 19, 63, 216, 760]);
+        assert!(Polyomino::enumerate(0, Equivalence::Free).is_empty());
+    }
+
+    #[test]
+    fn classifies_pairs_of_shapes() {
+        let l: Polyomino = "#.\n#.\n##".parse().unwrap();
+        let turned = l.rotate_90();
+        let mirrored = l.flip_horizontal();
+        let square: Polyomino = "##\n##".parse().unwrap();
+
+        assert_eq!(l.classify(&l), Some(Equivalence::Fixed));
+        assert_eq!(l.classify(&turned), Some(Equivalence::OneSided));
+        assert_eq!(l.classify(&mirrored), Some(Equivalence::Free));
+        assert_eq!(l.classify(&square), None);
+
+        assert_eq!(
+            mirrored.canonical(Equivalence::Free),
+            l.canonical(Equivalence::Free)
+        );
+        assert_eq!(l.canonical(Equivalence::Free).to_string(), "###\n#..");
+    }
+
+    #[test]
+    fn measures_and_draws_shapes() {
+        let u: Polyomino = "#.#\n###".parse().unwrap();
+        assert_eq!(u.bounding_box(), (2, 3));
+        assert_eq!(u.perimeter(), 12);
+        assert_eq!(u.to_string(), "#.#\n###");
+
+        // The ring's perimeter counts the edges round its hole too.
+        let ring: Polyomino = "###\n#.#\n###".parse().unwrap();
+        assert_eq!(ring.perimeter(), 16);
+        assert!("#x".parse::<Polyomino>().is_err());
+    }
+}